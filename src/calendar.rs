@@ -0,0 +1,1177 @@
+use crate::{
+    clock::SystemClock,
+    duration::{Days, Months, Seconds, Years},
+    prelude::*,
+};
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// A TimePoint of the SystemClock with a precision of days, equivalent to std::chrono::sys_days
+pub type SysDays = TimePoint<SystemClock, Days>;
+
+/// The Error returned when a calendar conversion fails
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CalendarError {
+    /// The date does not exist, such as February 30th, or has an invalid field
+    InvalidDate,
+    /// The date is outside of the range of Year
+    OutOfRange,
+    /// A TimePoint cannot be converted to or from a date exactly
+    Cast(DurationCastError),
+}
+
+impl Display for CalendarError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CalendarError::InvalidDate => f.write_str("invalid date"),
+            CalendarError::OutOfRange => f.write_str("date is outside of the range of Year"),
+            CalendarError::Cast(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for CalendarError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CalendarError::Cast(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DurationCastError> for CalendarError {
+    fn from(e: DurationCastError) -> Self {
+        CalendarError::Cast(e)
+    }
+}
+
+/// A day of a month, equivalent to std::chrono::day. A Day is valid if it is in the range [1, 31].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+impl Day {
+    /// Constructs a Day from its value, which need not be valid
+    pub const fn new(d: u8) -> Self {
+        Self(d)
+    }
+
+    /// Obtains the value of the Day
+    pub const fn into_inner(self) -> u8 {
+        self.0
+    }
+
+    /// Checks if the Day is in the range [1, 31]
+    pub const fn is_ok(self) -> bool {
+        self.0 >= 1 && self.0 <= 31
+    }
+}
+
+/// Formats the Day as two digits, such as 05, followed by " is not a valid day" if it is not valid
+impl Display for Day {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)?;
+        if !self.is_ok() {
+            f.write_str(" is not a valid day")?;
+        }
+        Ok(())
+    }
+}
+
+/// A month of a year, equivalent to std::chrono::month. A Month is valid if it is in the range [1, 12].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Month(u8);
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+impl Month {
+    pub const JANUARY: Month = Month(1);
+    pub const FEBRUARY: Month = Month(2);
+    pub const MARCH: Month = Month(3);
+    pub const APRIL: Month = Month(4);
+    pub const MAY: Month = Month(5);
+    pub const JUNE: Month = Month(6);
+    pub const JULY: Month = Month(7);
+    pub const AUGUST: Month = Month(8);
+    pub const SEPTEMBER: Month = Month(9);
+    pub const OCTOBER: Month = Month(10);
+    pub const NOVEMBER: Month = Month(11);
+    pub const DECEMBER: Month = Month(12);
+
+    /// Constructs a Month from its value, which need not be valid
+    pub const fn new(m: u8) -> Self {
+        Self(m)
+    }
+
+    /// Obtains the value of the Month
+    pub const fn into_inner(self) -> u8 {
+        self.0
+    }
+
+    /// Checks if the Month is in the range [1, 12]
+    pub const fn is_ok(self) -> bool {
+        self.0 >= 1 && self.0 <= 12
+    }
+}
+
+/// Formats the Month as its abbreviated English name, such as Jan, or as its value followed by " is not a valid month" if it is not valid
+impl Display for Month {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.is_ok() {
+            f.write_str(MONTH_NAMES[self.0 as usize - 1])
+        } else {
+            write!(f, "{} is not a valid month", self.0)
+        }
+    }
+}
+
+/// A year of the proleptic Gregorian calendar, equivalent to std::chrono::year.
+/// Unlike std::chrono::year, every i32 value is a valid Year, and year 0 is 1 BC.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(i32);
+
+impl Year {
+    pub const MIN: Year = Year(i32::MIN);
+    pub const MAX: Year = Year(i32::MAX);
+
+    /// Constructs a Year from its value
+    pub const fn new(y: i32) -> Self {
+        Self(y)
+    }
+
+    /// Obtains the value of the Year
+    pub const fn into_inner(self) -> i32 {
+        self.0
+    }
+
+    /// Checks if the Year is valid, which is always true
+    pub const fn is_ok(self) -> bool {
+        true
+    }
+
+    /// Checks if the Year is a leap year, that is, if it is divisible by 4, and either not divisible by 100, or divisible by 400
+    pub const fn is_leap(self) -> bool {
+        self.0 % 4 == 0 && (self.0 % 100 != 0 || self.0 % 400 == 0)
+    }
+}
+
+/// Formats the Year with at least four digits, such as 0042 or -0001
+impl Display for Year {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let width = if self.0 < 0 { 5 } else { 4 };
+        write!(f, "{:0width$}", self.0, width = width)
+    }
+}
+
+/// A day of the week, equivalent to std::chrono::weekday. A Weekday is valid if it is in the range [0, 6], where 0 is Sunday.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Weekday(u8);
+
+const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+impl Weekday {
+    pub const SUNDAY: Weekday = Weekday(0);
+    pub const MONDAY: Weekday = Weekday(1);
+    pub const TUESDAY: Weekday = Weekday(2);
+    pub const WEDNESDAY: Weekday = Weekday(3);
+    pub const THURSDAY: Weekday = Weekday(4);
+    pub const FRIDAY: Weekday = Weekday(5);
+    pub const SATURDAY: Weekday = Weekday(6);
+
+    /// Constructs a Weekday from its value, which need not be valid. As with std::chrono::weekday, 7 is also Sunday.
+    pub const fn new(wd: u8) -> Self {
+        Self(if wd == 7 { 0 } else { wd })
+    }
+
+    /// Obtains the value of the Weekday, in the range [0, 6] where 0 is Sunday, if it is valid
+    pub const fn c_encoding(self) -> u8 {
+        self.0
+    }
+
+    /// Obtains the value of the Weekday, in the range [1, 7] where 7 is Sunday, if it is valid
+    pub const fn iso_encoding(self) -> u8 {
+        if self.0 == 0 {
+            7
+        } else {
+            self.0
+        }
+    }
+
+    /// Checks if the Weekday is in the range [0, 6]
+    pub const fn is_ok(self) -> bool {
+        self.0 <= 6
+    }
+}
+
+/// Formats the Weekday as its abbreviated English name, such as Mon, or as its value followed by " is not a valid weekday" if it is not valid
+impl Display for Weekday {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.is_ok() {
+            f.write_str(WEEKDAY_NAMES[self.0 as usize])
+        } else {
+            write!(f, "{} is not a valid weekday", self.0)
+        }
+    }
+}
+
+/// Obtains the Weekday of a day, where 1970-01-01 is a Thursday
+impl From<SysDays> for Weekday {
+    fn from(days: SysDays) -> Self {
+        Self(weekday_from_days(days.into_inner().into_inner()))
+    }
+}
+
+/// Computes the c_encoding of the Weekday of a number of days since 1970-01-01
+const fn weekday_from_days(days: i64) -> u8 {
+    ((days.rem_euclid(7) + 4) % 7) as u8
+}
+
+impl Weekday {
+    /// Obtains the index-th occurrence of the Weekday in a month, equivalent to weekday::operator[](unsigned)
+    pub const fn indexed(self, index: u8) -> WeekdayIndexed {
+        WeekdayIndexed {
+            weekday: self,
+            index,
+        }
+    }
+
+    /// Obtains the last occurrence of the Weekday in a month, equivalent to weekday::operator[](last_spec)
+    pub const fn last(self) -> WeekdayLast {
+        WeekdayLast { weekday: self }
+    }
+}
+
+/// The index-th occurrence of a Weekday in a month, such as the third Monday, equivalent to std::chrono::weekday_indexed.
+/// A WeekdayIndexed is valid if the Weekday is valid, and the index is in the range [1, 5].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WeekdayIndexed {
+    weekday: Weekday,
+    index: u8,
+}
+
+impl WeekdayIndexed {
+    pub const fn new(weekday: Weekday, index: u8) -> Self {
+        Self { weekday, index }
+    }
+
+    pub const fn weekday(self) -> Weekday {
+        self.weekday
+    }
+
+    pub const fn index(self) -> u8 {
+        self.index
+    }
+
+    /// Checks if the Weekday is valid, and the index is in the range [1, 5]
+    pub const fn is_ok(self) -> bool {
+        self.weekday.is_ok() && self.index >= 1 && self.index <= 5
+    }
+}
+
+/// Formats as Weekday[index], such as Mon[3]
+impl Display for WeekdayIndexed {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.weekday, self.index)
+    }
+}
+
+/// The last occurrence of a Weekday in a month, equivalent to std::chrono::weekday_last
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WeekdayLast {
+    weekday: Weekday,
+}
+
+impl WeekdayLast {
+    pub const fn new(weekday: Weekday) -> Self {
+        Self { weekday }
+    }
+
+    pub const fn weekday(self) -> Weekday {
+        self.weekday
+    }
+
+    /// Checks if the Weekday is valid
+    pub const fn is_ok(self) -> bool {
+        self.weekday.is_ok()
+    }
+}
+
+/// Formats as Weekday[last], such as Fri[last]
+impl Display for WeekdayLast {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}[last]", self.weekday)
+    }
+}
+
+/// A Month of a specific Year, equivalent to std::chrono::year_month
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: Year,
+    month: Month,
+}
+
+impl YearMonth {
+    pub const fn new(year: Year, month: Month) -> Self {
+        Self { year, month }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    /// Checks if both the Year and Month are valid
+    pub const fn is_ok(self) -> bool {
+        self.year.is_ok() && self.month.is_ok()
+    }
+}
+
+/// Formats as Year/Month, such as 2024/Feb
+impl Display for YearMonth {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.month)
+    }
+}
+
+/// A Day of a specific Month in an unspecified Year, equivalent to std::chrono::month_day
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonthDay {
+    month: Month,
+    day: Day,
+}
+
+impl MonthDay {
+    pub const fn new(month: Month, day: Day) -> Self {
+        Self { month, day }
+    }
+
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    pub const fn day(self) -> Day {
+        self.day
+    }
+
+    /// Checks if the Month is valid, and the Day is valid in that Month in some Year, so that February 29th is valid
+    pub const fn is_ok(self) -> bool {
+        self.month.is_ok() && self.day.0 >= 1 && self.day.0 <= last_day(true, self.month.0)
+    }
+}
+
+/// Formats as Month/Day, such as Feb/29
+impl Display for MonthDay {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.month, self.day)
+    }
+}
+
+/// Obtains the number of days in month, which must be valid
+const fn last_day(leap: bool, month: u8) -> u8 {
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A date of the proleptic Gregorian calendar, equivalent to std::chrono::year_month_day
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonthDay {
+    year: Year,
+    month: Month,
+    day: Day,
+}
+
+impl YearMonthDay {
+    pub const fn new(year: Year, month: Month, day: Day) -> Self {
+        Self { year, month, day }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    pub const fn day(self) -> Day {
+        self.day
+    }
+
+    /// Checks if the Month is valid, and the Day is valid in that Month of the Year
+    pub const fn is_ok(self) -> bool {
+        self.month.is_ok()
+            && self.day.0 >= 1
+            && self.day.0 <= last_day(self.year.is_leap(), self.month.0)
+    }
+}
+
+/// Formats as an ISO 8601 date, such as 2024-02-29, followed by " is not a valid date" if it is not valid
+impl Display for YearMonthDay {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month.0, self.day.0)?;
+        if !self.is_ok() {
+            f.write_str(" is not a valid date")?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the number of days since 1970-01-01 of a valid date, using the days_from_civil algorithm of Howard Hinnant
+pub(crate) const fn days_from_civil(y: i32, m: u8, d: u8) -> i64 {
+    let y = y as i64 - if m <= 2 { 1 } else { 0 };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (m as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Computes the date of a number of days since 1970-01-01, using the civil_from_days algorithm of Howard Hinnant.
+/// The days must be within the range of Year, so that the computation cannot overflow.
+pub(crate) const fn civil_from_days(z: i64) -> (i64, u8, u8) {
+    let z = z + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    (yoe + era * 400 + if m <= 2 { 1 } else { 0 }, m, d)
+}
+
+/// The days of the first and last dates in the range of Year
+const MIN_DAYS: i64 = days_from_civil(i32::MIN, 1, 1);
+const MAX_DAYS: i64 = days_from_civil(i32::MAX, 12, 31);
+
+/// Converts a valid YearMonthDay to the day it represents, failing with CalendarError::InvalidDate if it is not valid.
+/// Every valid YearMonthDay can be represented exactly.
+impl TryFrom<YearMonthDay> for SysDays {
+    type Error = CalendarError;
+
+    fn try_from(ymd: YearMonthDay) -> Result<Self, CalendarError> {
+        if !ymd.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        Ok(TimePoint::new(Duration::new(days_from_civil(
+            ymd.year.0,
+            ymd.month.0,
+            ymd.day.0,
+        ))))
+    }
+}
+
+/// Converts a day to the date it falls on, failing with CalendarError::OutOfRange if the year is outside of the range of Year.
+impl TryFrom<SysDays> for YearMonthDay {
+    type Error = CalendarError;
+
+    fn try_from(days: SysDays) -> Result<Self, CalendarError> {
+        let days = days.into_inner().into_inner();
+        if !(MIN_DAYS..=MAX_DAYS).contains(&days) {
+            return Err(CalendarError::OutOfRange);
+        }
+        let (y, m, d) = civil_from_days(days);
+        Ok(Self::new(Year(y as i32), Month(m), Day(d)))
+    }
+}
+
+/// The last day of a specific Month and Year, equivalent to std::chrono::year_month_day_last
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonthDayLast {
+    year: Year,
+    month: Month,
+}
+
+impl YearMonthDayLast {
+    pub const fn new(year: Year, month: Month) -> Self {
+        Self { year, month }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    /// Obtains the last Day of the Month, which is unspecified if the Month is not valid
+    pub const fn day(self) -> Day {
+        if self.month.is_ok() {
+            Day(last_day(self.year.is_leap(), self.month.0))
+        } else {
+            Day(0)
+        }
+    }
+
+    /// Checks if the Month is valid
+    pub const fn is_ok(self) -> bool {
+        self.year.is_ok() && self.month.is_ok()
+    }
+}
+
+/// Formats as Year/Month/last, such as 2024/Feb/last
+impl Display for YearMonthDayLast {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}/last", self.year, self.month)
+    }
+}
+
+impl From<YearMonthDayLast> for YearMonthDay {
+    fn from(ymdl: YearMonthDayLast) -> Self {
+        Self::new(ymdl.year, ymdl.month, ymdl.day())
+    }
+}
+
+/// Converts a valid YearMonthDayLast to the day it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<YearMonthDayLast> for SysDays {
+    type Error = CalendarError;
+
+    fn try_from(ymdl: YearMonthDayLast) -> Result<Self, CalendarError> {
+        SysDays::try_from(YearMonthDay::from(ymdl))
+    }
+}
+
+/// Converts a day to the YearMonthDayLast of its month, failing with CalendarError::InvalidDate if it is not the last day of its month,
+/// or with CalendarError::OutOfRange if the year is outside of the range of Year.
+impl TryFrom<SysDays> for YearMonthDayLast {
+    type Error = CalendarError;
+
+    fn try_from(days: SysDays) -> Result<Self, CalendarError> {
+        let ymd = YearMonthDay::try_from(days)?;
+        let ymdl = Self::new(ymd.year, ymd.month);
+        if ymdl.day() == ymd.day {
+            Ok(ymdl)
+        } else {
+            Err(CalendarError::InvalidDate)
+        }
+    }
+}
+
+/// The index-th occurrence of a Weekday in a specific Month and Year, such as the second Tuesday of 2024/Jan,
+/// equivalent to std::chrono::year_month_weekday
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct YearMonthWeekday {
+    year: Year,
+    month: Month,
+    weekday_indexed: WeekdayIndexed,
+}
+
+impl YearMonthWeekday {
+    pub const fn new(year: Year, month: Month, weekday_indexed: WeekdayIndexed) -> Self {
+        Self {
+            year,
+            month,
+            weekday_indexed,
+        }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    pub const fn weekday(self) -> Weekday {
+        self.weekday_indexed.weekday
+    }
+
+    pub const fn index(self) -> u8 {
+        self.weekday_indexed.index
+    }
+
+    pub const fn weekday_indexed(self) -> WeekdayIndexed {
+        self.weekday_indexed
+    }
+
+    /// Obtains the day of the month of the index-th occurrence of the Weekday, which may be past the end of the month
+    const fn day_of_month(self) -> u8 {
+        let first = weekday_from_days(days_from_civil(self.year.0, self.month.0, 1));
+        let offset = (self.weekday_indexed.weekday.0 + 7 - first) % 7;
+        1 + offset + (self.weekday_indexed.index - 1) * 7
+    }
+
+    /// Checks if the Month and WeekdayIndexed are valid, and the index-th occurrence of the Weekday exists in the Month,
+    /// so that the fifth Wednesday is only valid in months with five Wednesdays
+    pub const fn is_ok(self) -> bool {
+        self.year.is_ok()
+            && self.month.is_ok()
+            && self.weekday_indexed.is_ok()
+            && self.day_of_month() <= last_day(self.year.is_leap(), self.month.0)
+    }
+}
+
+/// Formats as Year/Month/Weekday[index], such as 2024/Jan/Tue[2]
+impl Display for YearMonthWeekday {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.year, self.month, self.weekday_indexed)
+    }
+}
+
+/// Converts a valid YearMonthWeekday to the date it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<YearMonthWeekday> for YearMonthDay {
+    type Error = CalendarError;
+
+    fn try_from(ymwd: YearMonthWeekday) -> Result<Self, CalendarError> {
+        if !ymwd.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        Ok(Self::new(ymwd.year, ymwd.month, Day(ymwd.day_of_month())))
+    }
+}
+
+/// Converts a valid YearMonthWeekday to the day it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<YearMonthWeekday> for SysDays {
+    type Error = CalendarError;
+
+    fn try_from(ymwd: YearMonthWeekday) -> Result<Self, CalendarError> {
+        SysDays::try_from(YearMonthDay::try_from(ymwd)?)
+    }
+}
+
+/// Converts a day to the occurrence of its Weekday in its month, failing with CalendarError::OutOfRange if the year is outside of the range of Year.
+impl TryFrom<SysDays> for YearMonthWeekday {
+    type Error = CalendarError;
+
+    fn try_from(days: SysDays) -> Result<Self, CalendarError> {
+        let ymd = YearMonthDay::try_from(days)?;
+        let weekday = Weekday::from(days);
+        Ok(Self::new(
+            ymd.year,
+            ymd.month,
+            weekday.indexed((ymd.day.0 - 1) / 7 + 1),
+        ))
+    }
+}
+
+/// The last occurrence of a Weekday in a specific Month and Year, such as the last Friday of 2024/Jan,
+/// equivalent to std::chrono::year_month_weekday_last
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct YearMonthWeekdayLast {
+    year: Year,
+    month: Month,
+    weekday_last: WeekdayLast,
+}
+
+impl YearMonthWeekdayLast {
+    pub const fn new(year: Year, month: Month, weekday_last: WeekdayLast) -> Self {
+        Self {
+            year,
+            month,
+            weekday_last,
+        }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    pub const fn weekday(self) -> Weekday {
+        self.weekday_last.weekday
+    }
+
+    pub const fn weekday_last(self) -> WeekdayLast {
+        self.weekday_last
+    }
+
+    /// Obtains the day of the month of the last occurrence of the Weekday
+    const fn day_of_month(self) -> u8 {
+        let last = last_day(self.year.is_leap(), self.month.0);
+        let last_weekday = weekday_from_days(days_from_civil(self.year.0, self.month.0, last));
+        last - (last_weekday + 7 - self.weekday_last.weekday.0) % 7
+    }
+
+    /// Checks if the Month and WeekdayLast are valid
+    pub const fn is_ok(self) -> bool {
+        self.year.is_ok() && self.month.is_ok() && self.weekday_last.is_ok()
+    }
+}
+
+/// Formats as Year/Month/Weekday[last], such as 2024/Jan/Fri[last]
+impl Display for YearMonthWeekdayLast {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.year, self.month, self.weekday_last)
+    }
+}
+
+/// Converts a valid YearMonthWeekdayLast to the date it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<YearMonthWeekdayLast> for YearMonthDay {
+    type Error = CalendarError;
+
+    fn try_from(ymwdl: YearMonthWeekdayLast) -> Result<Self, CalendarError> {
+        if !ymwdl.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        Ok(Self::new(
+            ymwdl.year,
+            ymwdl.month,
+            Day(ymwdl.day_of_month()),
+        ))
+    }
+}
+
+/// Converts a valid YearMonthWeekdayLast to the day it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<YearMonthWeekdayLast> for SysDays {
+    type Error = CalendarError;
+
+    fn try_from(ymwdl: YearMonthWeekdayLast) -> Result<Self, CalendarError> {
+        SysDays::try_from(YearMonthDay::try_from(ymwdl)?)
+    }
+}
+
+/// Converts a day to the YearMonthWeekdayLast of its Weekday and month, failing with CalendarError::InvalidDate
+/// if it is not the last occurrence of its Weekday in its month, or with CalendarError::OutOfRange if the year is outside of the range of Year.
+impl TryFrom<SysDays> for YearMonthWeekdayLast {
+    type Error = CalendarError;
+
+    fn try_from(days: SysDays) -> Result<Self, CalendarError> {
+        let ymd = YearMonthDay::try_from(days)?;
+        let ymwdl = Self::new(ymd.year, ymd.month, Weekday::from(days).last());
+        if ymwdl.day_of_month() == ymd.day.0 {
+            Ok(ymwdl)
+        } else {
+            Err(CalendarError::InvalidDate)
+        }
+    }
+}
+
+/// The policy for resolving a day which does not exist in the resulting month of calendrical arithmetic, such as 2024-01-31 plus one month
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayOverflow {
+    /// Use the last day of the month, such that 2024-01-31 plus one month is 2024-02-29
+    Clamp,
+    /// Carry the excess days into the following month, such that 2024-01-31 plus one month is 2024-03-02
+    Overflow,
+    /// Fail with CalendarError::InvalidDate
+    Error,
+}
+
+impl YearMonth {
+    /// Offsets the YearMonth by a number of calendar months, failing with CalendarError::InvalidDate if the Month is not valid,
+    /// or with CalendarError::OutOfRange if the result is outside of the range of Year.
+    pub fn add_months(self, months: Months) -> Result<Self, CalendarError> {
+        if !self.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        let total =
+            (self.year.0 as i128) * 12 + (self.month.0 as i128 - 1) + months.into_inner() as i128;
+        let year = i32::try_from(total.div_euclid(12)).map_err(|_| CalendarError::OutOfRange)?;
+        Ok(Self::new(Year(year), Month(total.rem_euclid(12) as u8 + 1)))
+    }
+
+    /// Offsets the YearMonth by a number of calendar years, failing with CalendarError::InvalidDate if the Month is not valid,
+    /// or with CalendarError::OutOfRange if the result is outside of the range of Year.
+    pub fn add_years(self, years: Years) -> Result<Self, CalendarError> {
+        if !self.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        let year = (self.year.0 as i64)
+            .checked_add(years.into_inner())
+            .and_then(|y| i32::try_from(y).ok())
+            .ok_or(CalendarError::OutOfRange)?;
+        Ok(Self::new(Year(year), self.month))
+    }
+}
+
+impl YearMonthDay {
+    /// Obtains the YearMonth of the date
+    pub const fn year_month(self) -> YearMonth {
+        YearMonth::new(self.year, self.month)
+    }
+
+    /// Resolves day in ym according to policy
+    fn resolve(ym: YearMonth, day: Day, policy: DayOverflow) -> Result<Self, CalendarError> {
+        let last = last_day(ym.year.is_leap(), ym.month.0);
+        if day.0 <= last {
+            return Ok(Self::new(ym.year, ym.month, day));
+        }
+        match policy {
+            DayOverflow::Clamp => Ok(Self::new(ym.year, ym.month, Day(last))),
+            DayOverflow::Overflow => {
+                let days = days_from_civil(ym.year.0, ym.month.0, last) + (day.0 - last) as i64;
+                Self::try_from(SysDays::new(Duration::new(days)))
+            }
+            DayOverflow::Error => Err(CalendarError::InvalidDate),
+        }
+    }
+}
+
+/// Calendrical arithmetic, which offsets dates and date-times by calendar months and years, rather than by the average lengths of Months and Years
+pub trait CalendarArithmetic: Sized {
+    /// Offsets by a number of calendar months, resolving a day past the end of the resulting month according to policy.
+    /// The time of day, if any, is unchanged.
+    fn add_months(self, months: Months, policy: DayOverflow) -> Result<Self, CalendarError>;
+    /// Offsets by a number of calendar years, resolving February 29th in a non-leap year according to policy.
+    /// The time of day, if any, is unchanged.
+    fn add_years(self, years: Years, policy: DayOverflow) -> Result<Self, CalendarError>;
+    /// Obtains the YearMonth which contains self
+    fn year_month(&self) -> Result<YearMonth, CalendarError>;
+}
+
+/// Fails with CalendarError::InvalidDate if the date is not valid
+impl CalendarArithmetic for YearMonthDay {
+    fn add_months(self, months: Months, policy: DayOverflow) -> Result<Self, CalendarError> {
+        if !self.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        Self::resolve(self.year_month().add_months(months)?, self.day, policy)
+    }
+
+    fn add_years(self, years: Years, policy: DayOverflow) -> Result<Self, CalendarError> {
+        if !self.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        Self::resolve(self.year_month().add_years(years)?, self.day, policy)
+    }
+
+    fn year_month(&self) -> Result<YearMonth, CalendarError> {
+        Ok(YearMonthDay::year_month(*self))
+    }
+}
+
+impl<R: DurationRepr, P: Period> TimePoint<SystemClock, Duration<R, P>> {
+    /// Applies f to the date of the TimePoint, keeping the time of day
+    fn map_date<F>(self, f: F) -> Result<Self, CalendarError>
+    where
+        F: FnOnce(YearMonthDay) -> Result<YearMonthDay, CalendarError>,
+    {
+        let value = self.into_inner();
+        let days: Days = DurationCast::duration_floor(value.clone())?;
+        let date = f(YearMonthDay::try_from(SysDays::new(days))?)?;
+        let delta = SysDays::try_from(date)?.into_inner().into_inner() - days.into_inner();
+        Ok(TimePoint::new(value)
+            .offset_exact(Nanoseconds::new(delta as i128 * 86_400_000_000_000))?)
+    }
+
+    /// Offsets the TimePoint by offset, failing if the offset or the result cannot be represented exactly by the Duration.
+    /// The value is offset directly, so that the time of day is kept exactly.
+    pub(crate) fn offset_exact(self, offset: Nanoseconds) -> Result<Self, DurationCastError> {
+        let value = self.into_inner().into_inner();
+        let value = if R::TREAT_AS_FLOATING_POINT {
+            let offset =
+                Duration::<f64, Nano>::new(offset.into_inner() as f64).cast_exact::<f64, P>()?;
+            R::from_f64(value.to_f64() + offset.into_inner())?
+        } else {
+            let offset = offset.cast_exact::<i128, P>()?.into_inner();
+            let value = value.to_i128()?.checked_add(offset).ok_or(if offset < 0 {
+                DurationCastError::Underflow
+            } else {
+                DurationCastError::Overflow
+            })?;
+            R::from_i128(value)?
+        };
+        Ok(TimePoint::new(Duration::new(value)))
+    }
+}
+
+/// Offsets the date of the TimePoint, keeping the time of day exactly.
+/// Fails with CalendarError::Cast if the result cannot be represented exactly by the Duration, such as when the Period is longer than a day.
+impl<R: DurationRepr, P: Period> CalendarArithmetic for TimePoint<SystemClock, Duration<R, P>> {
+    fn add_months(self, months: Months, policy: DayOverflow) -> Result<Self, CalendarError> {
+        self.map_date(|date| date.add_months(months, policy))
+    }
+
+    fn add_years(self, years: Years, policy: DayOverflow) -> Result<Self, CalendarError> {
+        self.map_date(|date| date.add_years(years, policy))
+    }
+
+    fn year_month(&self) -> Result<YearMonth, CalendarError> {
+        let days: Days = DurationCast::duration_floor(self.clone().into_inner())?;
+        Ok(YearMonthDay::try_from(SysDays::new(days))?.year_month())
+    }
+}
+
+/// Computes the number of whole calendar months from from to to, which is negative if to is before from.
+///
+/// The result is the greatest number of months n, such that adding n months to from, using DayOverflow::Clamp, does not pass to,
+/// such that there is one month from 2024-01-31 to 2024-02-29.
+pub fn months_between<T: CalendarArithmetic + PartialOrd>(
+    from: T,
+    to: T,
+) -> Result<Months, CalendarError> {
+    let (start, end) = (from.year_month()?, to.year_month()?);
+    let months = (end.year.0 as i64 - start.year.0 as i64) * 12
+        + (end.month.0 as i64 - start.month.0 as i64);
+    let candidate = from.add_months(Months::new(months), DayOverflow::Clamp)?;
+    let months = if months > 0 && candidate > to {
+        months - 1
+    } else if months < 0 && candidate < to {
+        months + 1
+    } else {
+        months
+    };
+    Ok(Months::new(months))
+}
+
+/// Computes the number of whole calendar years from from to to, which is negative if to is before from.
+///
+/// The result is the number of whole years in months_between(from, to).
+pub fn years_between<T: CalendarArithmetic + PartialOrd>(
+    from: T,
+    to: T,
+) -> Result<Years, CalendarError> {
+    Ok(Years::new(months_between(from, to)?.into_inner() / 12))
+}
+
+/// A date of the ISO 8601 week-numbering calendar, such as 2026-W42-3, which is a week-numbering year, a week of that year, and a Weekday.
+///
+/// Weeks begin on Monday, and week 1 of a week-numbering year is the week which contains January 4th,
+/// so that early January may belong to the last week of the previous week-numbering year, and late December to week 1 of the next.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IsoWeekDate {
+    year: Year,
+    week: u8,
+    weekday: Weekday,
+}
+
+/// Computes the day of Monday of week 1 of the week-numbering year y
+const fn iso_year_start(y: i32) -> i64 {
+    let jan4 = days_from_civil(y, 1, 4);
+    jan4 - (Weekday(weekday_from_days(jan4)).iso_encoding() as i64 - 1)
+}
+
+impl IsoWeekDate {
+    pub const fn new(year: Year, week: u8, weekday: Weekday) -> Self {
+        Self {
+            year,
+            week,
+            weekday,
+        }
+    }
+
+    /// Obtains the week-numbering year, which may differ from the calendar year near the start and end of a year
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn week(self) -> u8 {
+        self.week
+    }
+
+    pub const fn weekday(self) -> Weekday {
+        self.weekday
+    }
+
+    /// Obtains the number of weeks in the week-numbering year, which is 53 if the year begins on a Thursday,
+    /// or is a leap year which begins on a Wednesday, and 52 otherwise
+    pub const fn weeks_in_year(year: Year) -> u8 {
+        let jan1 = weekday_from_days(days_from_civil(year.0, 1, 1));
+        if jan1 == Weekday::THURSDAY.0 || (year.is_leap() && jan1 == Weekday::WEDNESDAY.0) {
+            53
+        } else {
+            52
+        }
+    }
+
+    /// Checks if the week is in the range [1, weeks_in_year], and the Weekday is valid
+    pub const fn is_ok(self) -> bool {
+        self.week >= 1 && self.week <= Self::weeks_in_year(self.year) && self.weekday.is_ok()
+    }
+}
+
+/// Formats in the ISO 8601 week date format, such as 2026-W42-3, where the Weekday is in the range [1, 7] and 1 is Monday
+impl Display for IsoWeekDate {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-W{:02}-{}",
+            self.year,
+            self.week,
+            self.weekday.iso_encoding()
+        )?;
+        if !self.is_ok() {
+            f.write_str(" is not a valid date")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a valid IsoWeekDate to the day it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<IsoWeekDate> for SysDays {
+    type Error = CalendarError;
+
+    fn try_from(date: IsoWeekDate) -> Result<Self, CalendarError> {
+        if !date.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        let days = iso_year_start(date.year.0)
+            + (date.week as i64 - 1) * 7
+            + (date.weekday.iso_encoding() as i64 - 1);
+        Ok(TimePoint::new(Duration::new(days)))
+    }
+}
+
+/// Converts a day to the week date it falls on, failing with CalendarError::OutOfRange if the week-numbering year is outside of the range of Year.
+impl TryFrom<SysDays> for IsoWeekDate {
+    type Error = CalendarError;
+
+    fn try_from(days: SysDays) -> Result<Self, CalendarError> {
+        let weekday = Weekday::from(days);
+        let days = days.into_inner().into_inner();
+        // The week-numbering year differs from the calendar year by at most one, and only within a week of the start of the year
+        if !(MIN_DAYS - 7..=MAX_DAYS + 7).contains(&days) {
+            return Err(CalendarError::OutOfRange);
+        }
+        let (y, _, _) = civil_from_days(days);
+        let (year, start) = [y + 1, y, y - 1]
+            .iter()
+            .filter_map(|&y| i32::try_from(y).ok())
+            .map(|y| (y, iso_year_start(y)))
+            .find(|&(_, start)| days >= start)
+            .ok_or(CalendarError::OutOfRange)?;
+        let week = (days - start) / 7 + 1;
+        if week > IsoWeekDate::weeks_in_year(Year(year)) as i64 {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(IsoWeekDate::new(Year(year), week as u8, weekday))
+    }
+}
+
+/// A date as a year and a day of that year, such as 2026-291, where January 1st is day 1, equivalent to an ISO 8601 ordinal date
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OrdinalDate {
+    year: Year,
+    day: u16,
+}
+
+impl OrdinalDate {
+    pub const fn new(year: Year, day: u16) -> Self {
+        Self { year, day }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    /// Obtains the day of the year, where January 1st is day 1
+    pub const fn day(self) -> u16 {
+        self.day
+    }
+
+    /// Obtains the number of days in year, which is 366 in a leap year, and 365 otherwise
+    pub const fn days_in_year(year: Year) -> u16 {
+        if year.is_leap() {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Checks if the day is in the range [1, days_in_year]
+    pub const fn is_ok(self) -> bool {
+        self.day >= 1 && self.day <= Self::days_in_year(self.year)
+    }
+}
+
+/// Formats in the ISO 8601 ordinal date format, such as 2026-291, followed by " is not a valid date" if it is not valid
+impl Display for OrdinalDate {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}-{:03}", self.year, self.day)?;
+        if !self.is_ok() {
+            f.write_str(" is not a valid date")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a valid OrdinalDate to the day it represents, failing with CalendarError::InvalidDate if it is not valid.
+impl TryFrom<OrdinalDate> for SysDays {
+    type Error = CalendarError;
+
+    fn try_from(date: OrdinalDate) -> Result<Self, CalendarError> {
+        if !date.is_ok() {
+            return Err(CalendarError::InvalidDate);
+        }
+        Ok(TimePoint::new(Duration::new(
+            days_from_civil(date.year.0, 1, 1) + date.day as i64 - 1,
+        )))
+    }
+}
+
+/// Converts a day to the ordinal date it falls on, failing with CalendarError::OutOfRange if the year is outside of the range of Year.
+impl TryFrom<SysDays> for OrdinalDate {
+    type Error = CalendarError;
+
+    fn try_from(days: SysDays) -> Result<Self, CalendarError> {
+        let year = YearMonthDay::try_from(days)?.year;
+        let day = days.into_inner().into_inner() - days_from_civil(year.0, 1, 1) + 1;
+        Ok(Self::new(year, day as u16))
+    }
+}
+
+/// A fractional number of days, as used for Julian Days and Modified Julian Dates
+pub type FractionalDays = Duration<f64, Ratio<86400, 1>>;
+
+/// The time from the epoch of the Julian Day, noon of -4713-11-24, to 1970-01-01T00:00:00, which is JD 2440587.5.
+///
+/// The Julian Day of a TimePoint in the Repr and Period of its Duration, without the limited precision of julian_day, is `tp.into_inner() + JULIAN_DAY_OFFSET`.
+pub const JULIAN_DAY_OFFSET: Seconds = Seconds::new(210_866_760_000);
+/// The time from the epoch of the Modified Julian Date, 1858-11-17T00:00:00, to 1970-01-01T00:00:00, which is MJD 40587.
+///
+/// The Modified Julian Date of a TimePoint in the Repr and Period of its Duration, without the limited precision of modified_julian_date,
+/// is `tp.into_inner() + MODIFIED_JULIAN_DATE_OFFSET`.
+pub const MODIFIED_JULIAN_DATE_OFFSET: Seconds = Seconds::new(3_506_716_800);
+
+/// The Julian Day of 1970-01-01T00:00:00
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+/// The Modified Julian Date of 1970-01-01T00:00:00, where the Modified Julian Date is the Julian Day minus 2400000.5
+const UNIX_EPOCH_MODIFIED_JULIAN_DATE: f64 = 40587.0;
+
+/// Obtains the Julian Day of a TimePoint, which is the number of days since noon of -4713-11-24 in the proleptic Gregorian calendar, such as JD 2461000.5.
+///
+/// The Julian Day is computed in f64, and is therefore only precise to the resolution of f64 at its magnitude, not to the Period of the Duration:
+/// for current dates, that is 2^-31 days, about 40 microseconds. JULIAN_DAY_OFFSET computes the Julian Day exactly in the Period of the Duration.
+pub fn julian_day<R: DurationRepr, P: Period>(
+    tp: TimePoint<SystemClock, Duration<R, P>>,
+) -> Result<FractionalDays, DurationCastError> {
+    let days: FractionalDays = DurationCast::duration_cast(tp.into_inner())?;
+    Ok(Duration::new(days.into_inner() + UNIX_EPOCH_JULIAN_DAY))
+}
+
+/// Obtains the TimePoint of a Julian Day, rounded to the nearest value of the Duration.
+pub fn from_julian_day<R: DurationRepr, P: Period>(
+    jd: FractionalDays,
+) -> Result<TimePoint<SystemClock, Duration<R, P>>, DurationCastError> {
+    let days = FractionalDays::new(jd.into_inner() - UNIX_EPOCH_JULIAN_DAY);
+    DurationCast::duration_round(days).map(TimePoint::new)
+}
+
+/// Obtains the Modified Julian Date of a TimePoint, which is the number of days since 1858-11-17T00:00:00, such as MJD 61000.
+///
+/// The Modified Julian Date is computed in f64, and is therefore only precise to the resolution of f64 at its magnitude, not to the Period of the Duration:
+/// for current dates, that is 2^-37 days, about 0.6 microseconds. MODIFIED_JULIAN_DATE_OFFSET computes the Modified Julian Date exactly in the Period of the Duration.
+pub fn modified_julian_date<R: DurationRepr, P: Period>(
+    tp: TimePoint<SystemClock, Duration<R, P>>,
+) -> Result<FractionalDays, DurationCastError> {
+    let days: FractionalDays = DurationCast::duration_cast(tp.into_inner())?;
+    Ok(Duration::new(
+        days.into_inner() + UNIX_EPOCH_MODIFIED_JULIAN_DATE,
+    ))
+}
+
+/// Obtains the TimePoint of a Modified Julian Date, rounded to the nearest value of the Duration.
+pub fn from_modified_julian_date<R: DurationRepr, P: Period>(
+    mjd: FractionalDays,
+) -> Result<TimePoint<SystemClock, Duration<R, P>>, DurationCastError> {
+    let days = FractionalDays::new(mjd.into_inner() - UNIX_EPOCH_MODIFIED_JULIAN_DATE);
+    DurationCast::duration_round(days).map(TimePoint::new)
+}
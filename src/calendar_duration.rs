@@ -0,0 +1,168 @@
+use crate::{
+    calendar::{
+        months_between, CalendarArithmetic, CalendarError, DayOverflow, SysDays, YearMonthDay,
+    },
+    clock::SystemClock,
+    duration::{Days, Months, Years},
+    iso8601::write_time,
+    prelude::*,
+};
+
+use std::{
+    convert::TryFrom,
+    fmt::{Display, Formatter},
+};
+
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// A nominal quantity of years, months, and days, and an exact time, such as 1 year, 2 months, 3 days, and 4 hours.
+///
+/// Unlike Duration, the length of a CalendarDuration depends on the date it is added to, as years and months are calendar years and months,
+/// rather than the average lengths of Years and Months. Days are always 86400 seconds, as the SystemClock does not count leap seconds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CalendarDuration {
+    years: Years,
+    months: Months,
+    days: Days,
+    time: Nanoseconds,
+}
+
+impl CalendarDuration {
+    pub const ZERO: CalendarDuration = CalendarDuration::new(
+        Duration::new(0),
+        Duration::new(0),
+        Duration::new(0),
+        Duration::new(0),
+    );
+
+    pub const fn new(years: Years, months: Months, days: Days, time: Nanoseconds) -> Self {
+        Self {
+            years,
+            months,
+            days,
+            time,
+        }
+    }
+
+    pub fn years(&self) -> Years {
+        self.years
+    }
+
+    pub fn months(&self) -> Months {
+        self.months
+    }
+
+    pub fn days(&self) -> Days {
+        self.days
+    }
+
+    pub fn time(&self) -> Nanoseconds {
+        self.time
+    }
+
+    /// Computes the CalendarDuration from from to to, such as 3 years, 1 month, and 4 days, which has no time part.
+    ///
+    /// The years and months are the whole calendar months as by months_between, and the days are the remainder,
+    /// so that adding the result to from, using DayOverflow::Clamp, yields to. If to is before from, every component is negative or zero.
+    pub fn between_dates(from: YearMonthDay, to: YearMonthDay) -> Result<Self, CalendarError> {
+        let months = months_between(from, to)?;
+        let start = SysDays::try_from(from.add_months(months, DayOverflow::Clamp)?)?;
+        let days =
+            SysDays::try_from(to)?.into_inner().into_inner() - start.into_inner().into_inner();
+        Ok(Self::from_parts(months, days as i128 * NANOS_PER_DAY))
+    }
+
+    /// Computes the CalendarDuration from from to to, as by between_dates, with the remainder split into days and an exact time.
+    /// Fails with CalendarError::Cast if either TimePoint cannot be represented exactly in Nanoseconds.
+    pub fn between<R: DurationRepr + PartialOrd, P: Period>(
+        from: TimePoint<SystemClock, Duration<R, P>>,
+        to: TimePoint<SystemClock, Duration<R, P>>,
+    ) -> Result<Self, CalendarError> {
+        let months = months_between(from.clone(), to.clone())?;
+        let start = from.add_months(months, DayOverflow::Clamp)?;
+        let (start, end) = (
+            start.into_inner().cast_exact::<i128, Nano>()?,
+            to.into_inner().cast_exact::<i128, Nano>()?,
+        );
+        let remainder = end
+            .into_inner()
+            .checked_sub(start.into_inner())
+            .ok_or(DurationCastError::Overflow)?;
+        Ok(Self::from_parts(months, remainder))
+    }
+
+    /// Splits months into years and months, and nanos into days and time
+    fn from_parts(months: Months, nanos: i128) -> Self {
+        let months = months.into_inner();
+        Self::new(
+            Duration::new(months / 12),
+            Duration::new(months % 12),
+            Duration::new((nanos / NANOS_PER_DAY) as i64),
+            Duration::new(nanos % NANOS_PER_DAY),
+        )
+    }
+}
+
+impl<R: DurationRepr, P: Period> TimePoint<SystemClock, Duration<R, P>> {
+    /// Offsets the TimePoint by a CalendarDuration, first by its years and months as by CalendarArithmetic::add_months,
+    /// resolving a day past the end of the resulting month according to policy, and then by its days and time exactly.
+    ///
+    /// Fails with CalendarError::Cast if the result cannot be represented exactly by the Duration.
+    pub fn add_calendar_duration(
+        self,
+        d: CalendarDuration,
+        policy: DayOverflow,
+    ) -> Result<Self, CalendarError> {
+        let months = d
+            .years
+            .into_inner()
+            .checked_mul(12)
+            .and_then(|m| m.checked_add(d.months.into_inner()))
+            .ok_or(CalendarError::OutOfRange)?;
+        let offset = (d.days.into_inner() as i128 * NANOS_PER_DAY)
+            .checked_add(d.time.into_inner())
+            .ok_or(DurationCastError::Overflow)?;
+        Ok(self
+            .add_months(Duration::new(months), policy)?
+            .offset_exact(Duration::new(offset))?)
+    }
+}
+
+/// Formats as an ISO 8601 duration, such as P1Y2M3D, P1MT12H, or P0D.
+///
+/// If no component is positive, the duration is prefixed with a minus sign, such as -P1Y2M. Otherwise, negative components are prefixed with a minus sign,
+/// such as P1M-3D, which is not permitted by ISO 8601, but is accepted by many implementations.
+impl Display for CalendarDuration {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (years, months, days, time) = (
+            self.years.into_inner() as i128,
+            self.months.into_inner() as i128,
+            self.days.into_inner() as i128,
+            self.time.into_inner(),
+        );
+        let negative = years <= 0 && months <= 0 && days <= 0 && time <= 0;
+        if negative && (years, months, days, time) != (0, 0, 0, 0) {
+            f.write_str("-")?;
+        }
+        let sign = |v: i128| if v < 0 && !negative { "-" } else { "" };
+        f.write_str("P")?;
+        for (value, designator) in [(years, 'Y'), (months, 'M'), (days, 'D')] {
+            if value != 0 {
+                write!(f, "{}{}{}", sign(value), value.unsigned_abs(), designator)?;
+            }
+        }
+        if time != 0 {
+            let magnitude = time.unsigned_abs();
+            let fraction = format!("{:09}", magnitude % 1_000_000_000);
+            write_time(
+                f,
+                sign(time),
+                magnitude / 1_000_000_000,
+                fraction.trim_end_matches('0'),
+            )?;
+        } else if (years, months, days) == (0, 0, 0) {
+            f.write_str("0D")?;
+        }
+        Ok(())
+    }
+}
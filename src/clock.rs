@@ -1,7 +1,11 @@
 use clocks::HackForSoundness;
 
 use crate::prelude::*;
-use std::ops::*;
+use std::{
+    convert::TryFrom,
+    ops::*,
+    time::{Duration as StdDuration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 pub trait Clock: Sized {
     type Period: Period;
@@ -15,19 +19,27 @@ pub trait Clock: Sized {
 ///  Rem, PartialEq, PartialOrd, and Copy, and with a Duration Type which satisfies the above
 /// Additionally, the now function, of the implementation is known not to panic.
 ///
-/// Safety: A type which implements this trait MUST NOT panic in the now method.
+/// # Safety
+/// A type which implements this trait MUST NOT panic in the now method.
 /// Consumers of this trait may assume that the method is incapable of panicking
 pub unsafe trait TrivialClock: Clock
 where
     <Self as Clock>::Repr: Add + Sub + Div + Mul + PartialEq + PartialOrd + Copy,
-    <Self as Clock>::Duration: Add + Sub + Div + Mul + PartialEq + PartialOrd + Copy,
+    <Self as Clock>::Duration: Add
+        + Sub
+        + Div<<Self as Clock>::Repr>
+        + Mul<<Self as Clock>::Repr>
+        + PartialEq
+        + PartialOrd
+        + Copy,
 {
 }
 
 /// Represents a Clock that is monotonic or steady. A Monotonic Clock is a Clock which does not
 ///  reset in value, and which advances at a constant rate.
 ///
-/// Safety: A type which implements this trait must ensure that, given t1 = Self::now(); t2 = Self::now();,
+/// # Safety
+/// A type which implements this trait must ensure that, given t1 = Self::now(); t2 = Self::now();,
 /// and that the Self::now() call initializing t1 *happens-before* the Self::now() call initializing t2,
 /// t1 <= t2 is always true, and there is a consistent distance between ticks.
 pub unsafe trait Monotonic: Clock
@@ -54,7 +66,10 @@ pub enum SystemClock {}
 
 #[cfg(unix)]
 mod clocks {
-    use std::ops::{Add, Div, Mul};
+    use std::{
+        marker::PhantomData,
+        ops::{Add, Div, Mul},
+    };
 
     use libc::clock_gettime;
     use libc::CLOCK_REALTIME;
@@ -65,6 +80,22 @@ mod clocks {
 
     use crate::prelude::*;
 
+    /// The Numerator and Denominator of the Period P, which must fit in i64 as the clocks compute in i64
+    struct Factor<P>(PhantomData<P>);
+
+    const fn narrow(v: i128) -> i64 {
+        assert!(
+            v >= i64::MIN as i128 && v <= i64::MAX as i128,
+            "Clock Period overflows i64"
+        );
+        v as i64
+    }
+
+    impl<P: Period> Factor<P> {
+        const NUMERATOR: i64 = narrow(P::NUMERATOR);
+        const DENOMINATOR: i64 = narrow(P::DENOMINATOR);
+    }
+
     pub fn get_system_time<_Period: Period, Repr>() -> Repr
     where
         Repr: Mul<Output = Repr> + Div<Output = Repr> + Add<Output = Repr>,
@@ -72,12 +103,11 @@ mod clocks {
     {
         let mut ts: timespec = unsafe { core::mem::zeroed() };
         unsafe { clock_gettime(CLOCK_REALTIME, &mut ts) };
-        let val = ((Into::<Repr>::into(ts.tv_sec) * _Period::NUMERATOR.into())
-            / _Period::DENOMINATOR.into())
-            + ((Into::<Repr>::into(ts.tv_nsec) * RatioMultiply::<_Period, Nano>::NUMERATOR.into())
-                / RatioMultiply::<_Period, Nano>::DENOMINATOR.into());
-
-        val
+        ((Into::<Repr>::into(ts.tv_sec) * Factor::<RatioDivide<Unit, _Period>>::NUMERATOR.into())
+            / Factor::<RatioDivide<Unit, _Period>>::DENOMINATOR.into())
+            + ((Into::<Repr>::into(ts.tv_nsec)
+                * Factor::<RatioDivide<Nano, _Period>>::NUMERATOR.into())
+                / Factor::<RatioDivide<Nano, _Period>>::DENOMINATOR.into())
     }
 
     pub fn get_steady_time<_Period: Period, Repr>() -> Repr
@@ -87,12 +117,11 @@ mod clocks {
     {
         let mut ts: timespec = unsafe { core::mem::zeroed() };
         unsafe { clock_gettime(CLOCK_MONOTONIC, &mut ts) };
-        let val = ((Into::<Repr>::into(ts.tv_sec) * _Period::NUMERATOR.into())
-            / _Period::DENOMINATOR.into())
-            + ((Into::<Repr>::into(ts.tv_nsec) * RatioMultiply::<_Period, Nano>::NUMERATOR.into())
-                / RatioMultiply::<_Period, Nano>::DENOMINATOR.into());
-
-        val
+        ((Into::<Repr>::into(ts.tv_sec) * Factor::<RatioDivide<Unit, _Period>>::NUMERATOR.into())
+            / Factor::<RatioDivide<Unit, _Period>>::DENOMINATOR.into())
+            + ((Into::<Repr>::into(ts.tv_nsec)
+                * Factor::<RatioDivide<Nano, _Period>>::NUMERATOR.into())
+                / Factor::<RatioDivide<Nano, _Period>>::DENOMINATOR.into())
     }
 
     // Hack for soundness of impl TrivialClock for {System,Steady}Clock.
@@ -151,6 +180,83 @@ impl Clock for SystemClock {
 
 unsafe impl TrivialClock for SystemClock {}
 
+/// Converts a SystemTime to a TimePoint of the SystemClock, failing if the result overflows, or cannot be represented exactly.
+impl<R: DurationRepr, P: Period> TryFrom<SystemTime> for TimePoint<SystemClock, Duration<R, P>> {
+    type Error = DurationCastError;
+
+    fn try_from(t: SystemTime) -> Result<Self, DurationCastError> {
+        nanos_since_epoch(t).cast_exact().map(TimePoint::new)
+    }
+}
+
+/// Converts a SystemTime to a TimePoint of the SystemClock, rounding towards negative infinity, as by time_point_floor,
+/// such that a SystemTime with a finer precision than the TimePoint can be converted. Fails if the result overflows.
+pub fn system_time_floor<R: DurationRepr, P: Period>(
+    t: SystemTime,
+) -> Result<TimePoint<SystemClock, Duration<R, P>>, DurationCastError> {
+    DurationCast::<Duration<R, P>>::duration_floor(nanos_since_epoch(t)).map(TimePoint::new)
+}
+
+/// Obtains the (possibly negative) number of nanoseconds between the unix epoch and a SystemTime
+fn nanos_since_epoch(t: SystemTime) -> Nanoseconds {
+    // at most u64::MAX seconds, which is well within the range of i128 nanoseconds
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => Nanoseconds::new(d.as_nanos() as i128),
+        Err(e) => Nanoseconds::new(-(e.duration().as_nanos() as i128)),
+    }
+}
+
+/// Converts a TimePoint of the SystemClock to a SystemTime, failing if the result overflows, or cannot be represented exactly.
+impl<R: DurationRepr, P: Period> TryFrom<TimePoint<SystemClock, Duration<R, P>>> for SystemTime {
+    type Error = DurationCastError;
+
+    fn try_from(tp: TimePoint<SystemClock, Duration<R, P>>) -> Result<Self, DurationCastError> {
+        let since_epoch = tp.into_inner().cast_exact::<i128, Nano>()?;
+        offset_by(UNIX_EPOCH, since_epoch)
+    }
+}
+
+/// Offsets a SystemTime or Instant by a (possibly negative) number of nanoseconds
+fn offset_by<T>(base: T, offset: Nanoseconds) -> Result<T, DurationCastError>
+where
+    T: CheckedOffset,
+{
+    let magnitude =
+        StdDuration::try_from(offset.checked_abs().ok_or(DurationCastError::Underflow)?)?;
+    if offset.is_negative() {
+        base.checked_sub(magnitude)
+            .ok_or(DurationCastError::Underflow)
+    } else {
+        base.checked_add(magnitude)
+            .ok_or(DurationCastError::Overflow)
+    }
+}
+
+trait CheckedOffset: Sized {
+    fn checked_add(&self, d: StdDuration) -> Option<Self>;
+    fn checked_sub(&self, d: StdDuration) -> Option<Self>;
+}
+
+impl CheckedOffset for SystemTime {
+    fn checked_add(&self, d: StdDuration) -> Option<Self> {
+        SystemTime::checked_add(self, d)
+    }
+
+    fn checked_sub(&self, d: StdDuration) -> Option<Self> {
+        SystemTime::checked_sub(self, d)
+    }
+}
+
+impl CheckedOffset for Instant {
+    fn checked_add(&self, d: StdDuration) -> Option<Self> {
+        Instant::checked_add(self, d)
+    }
+
+    fn checked_sub(&self, d: StdDuration) -> Option<Self> {
+        Instant::checked_sub(self, d)
+    }
+}
+
 pub enum SteadyClock {}
 
 impl Clock for SteadyClock {
@@ -167,3 +273,21 @@ impl Clock for SteadyClock {
 
 unsafe impl TrivialClock for SteadyClock {}
 unsafe impl Monotonic for SteadyClock {}
+
+/// Converts a TimePoint of the SteadyClock to an Instant, such that it can be used as a deadline with the standard library.
+///
+/// As the epoch of Instant is unspecified, the conversion is performed relative to the current time of both clocks,
+/// failing if the result overflows, or cannot be represented exactly.
+impl<R: DurationRepr, P: Period> TryFrom<TimePoint<SteadyClock, Duration<R, P>>> for Instant {
+    type Error = DurationCastError;
+
+    fn try_from(tp: TimePoint<SteadyClock, Duration<R, P>>) -> Result<Self, DurationCastError> {
+        let deadline = tp.into_inner().cast_exact::<i128, Nano>()?;
+        let (now, instant) = (SteadyClock::now().into_inner(), Instant::now());
+        // now is never negative, so this can only underflow
+        let offset = deadline
+            .checked_sub(now)
+            .ok_or(DurationCastError::Underflow)?;
+        offset_by(instant, offset)
+    }
+}
@@ -1,21 +1,33 @@
 use bytemuck::{Pod, Zeroable};
 
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    ratio::{checked_ratio_divide, div_wide, mul_wide, named_periods},
+};
 use std::marker::PhantomData;
 
 use std::cmp::*;
 use std::ops::*;
 
 use std::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
+    error::Error,
+    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+    iter::Sum,
+    time::Duration as StdDuration,
 };
 
 #[cfg(feature = "step")]
 use std::iter::Step;
 
 /// Marker Trait equivalent to std::chrono::treat_as_floating_point
-/// presently unused, though in the future, it will be used to assist in the implementations of the From and DurationCast
+/// Durations with a Repr that implements this trait can be converted From Durations of any Period,
+/// as the conversion cannot truncate.
+///
+/// # Safety
+/// A type which implements this trait must behave like a floating-point type,
+/// that is, Division of the type must not truncate.
 pub unsafe trait TreatAsFloatingPoint {}
 
 unsafe impl TreatAsFloatingPoint for f32 {}
@@ -45,26 +57,26 @@ impl DurationValues for i16 {
 
 impl DurationValues for i32 {
     const ZERO: Self = 0;
-    const MIN: Self = i32::min_value();
-    const MAX: Self = i32::max_value();
+    const MIN: Self = i32::MIN;
+    const MAX: Self = i32::MAX;
 }
 
 impl DurationValues for i64 {
     const ZERO: Self = 0;
-    const MIN: Self = i64::min_value();
-    const MAX: Self = i64::max_value();
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
 }
 
 impl DurationValues for i128 {
     const ZERO: Self = 0;
-    const MIN: Self = i128::min_value();
-    const MAX: Self = i128::max_value();
+    const MIN: Self = i128::MIN;
+    const MAX: Self = i128::MAX;
 }
 
 impl DurationValues for isize {
     const ZERO: Self = 0;
-    const MIN: Self = isize::min_value();
-    const MAX: Self = isize::max_value();
+    const MIN: Self = isize::MIN;
+    const MAX: Self = isize::MAX;
 }
 
 impl DurationValues for u8 {
@@ -82,37 +94,273 @@ impl DurationValues for u16 {
 impl DurationValues for u32 {
     const ZERO: Self = 0;
     const MIN: Self = 0;
-    const MAX: Self = u32::max_value();
+    const MAX: Self = u32::MAX;
 }
 
 impl DurationValues for u64 {
     const ZERO: Self = 0;
     const MIN: Self = 0;
-    const MAX: Self = u64::max_value();
+    const MAX: Self = u64::MAX;
 }
 
 impl DurationValues for u128 {
     const ZERO: Self = 0;
     const MIN: Self = 0;
-    const MAX: Self = u128::max_value();
+    const MAX: Self = u128::MAX;
 }
 
 impl DurationValues for usize {
     const ZERO: Self = 0;
     const MIN: Self = 0;
-    const MAX: Self = usize::max_value();
+    const MAX: Self = usize::MAX;
 }
 
 impl DurationValues for f32 {
     const ZERO: Self = 0.0;
-    const MIN: Self = ::std::f32::MIN;
-    const MAX: Self = ::std::f32::MAX;
+    const MIN: Self = f32::MIN;
+    const MAX: Self = f32::MAX;
 }
 
 impl DurationValues for f64 {
     const ZERO: Self = 0.0;
-    const MIN: Self = ::std::f64::MIN;
-    const MAX: Self = ::std::f64::MAX;
+    const MIN: Self = f64::MIN;
+    const MAX: Self = f64::MAX;
+}
+
+/// Trait for Repr types which can be rescaled when converting a Duration between Periods.
+pub trait DurationRepr: Sized + Clone {
+    /// Whether DurationCast to or from the type is computed in floating-point, rather than exactly through the sign and magnitude intermediate.
+    /// This should be true exactly when the type implements TreatAsFloatingPoint.
+    const TREAT_AS_FLOATING_POINT: bool = false;
+    /// The greatest factor between two Periods that the type can represent, and therefore that From conversions between Periods may multiply by.
+    /// This is the maximum value of the type for integer types, saturated to i128::MAX.
+    const MAX_FACTOR: i128 = i128::MAX;
+    /// Multiplies the value by an integral factor between two Periods, as if by `self * factor`.
+    ///
+    /// If the result cannot be represented by the type, this overflows in the same way as the arithmetic of the type,
+    /// which for the primitive integer types panics in debug builds and wraps in release builds.
+    fn mul_factor(self, factor: i128) -> Self;
+    /// Divides the value by an integral factor between two Periods, as if by `self / factor`.
+    fn div_factor(self, factor: i128) -> Self;
+    /// Converts the value to an i128.
+    fn to_i128(self) -> Result<i128, DurationCastError>;
+    /// Converts the value to a sign and a magnitude, which is the intermediate used by DurationCast, and to compare Durations of different Periods exactly.
+    /// The default implementation converts through to_i128.
+    fn to_sign_magnitude(self) -> Result<(bool, u128), DurationCastError> {
+        self.to_i128()
+            .map(|value| (value < 0, value.unsigned_abs()))
+    }
+    /// Converts an i128 to a value of the type.
+    fn from_i128(value: i128) -> Result<Self, DurationCastError>;
+    /// Converts the sign and magnitude intermediate used by DurationCast to a value of the type.
+    /// The default implementation converts through from_i128.
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Result<Self, DurationCastError> {
+        if !negative {
+            i128::try_from(magnitude)
+                .map_err(|_| DurationCastError::Overflow)
+                .and_then(Self::from_i128)
+        } else if magnitude > i128::MIN.unsigned_abs() {
+            Err(DurationCastError::Underflow)
+        } else {
+            Self::from_i128((magnitude as i128).wrapping_neg())
+        }
+    }
+    /// Converts the value to the f64 intermediate used by DurationCast.
+    fn to_f64(self) -> f64;
+    /// Converts the f64 intermediate used by DurationCast to a value of the type.
+    /// The value has already been rounded if the type does not treat as floating-point.
+    fn from_f64(value: f64) -> Result<Self, DurationCastError>;
+}
+
+macro_rules! impl_duration_repr_int{
+    ($($ty:ty),*) => {
+        $(
+            impl DurationRepr for $ty {
+                const MAX_FACTOR: i128 = if <$ty>::MAX as u128 > i128::MAX as u128 {
+                    i128::MAX
+                } else {
+                    <$ty>::MAX as i128
+                };
+
+                fn mul_factor(self, factor: i128) -> Self {
+                    match <$ty as TryFrom<i128>>::try_from(factor) {
+                        Ok(factor) => self * factor,
+                        // The product may still be in range if the factor is not, such as when self is zero
+                        Err(_) if cfg!(debug_assertions) => self
+                            .to_i128()
+                            .ok()
+                            .and_then(|value| value.checked_mul(factor))
+                            .and_then(|value| Self::from_i128(value).ok())
+                            .expect("attempt to multiply a Duration by a Period factor with overflow"),
+                        // The product modulo 2^128 truncates to the product modulo the range of the type
+                        Err(_) => (self as i128).wrapping_mul(factor) as $ty,
+                    }
+                }
+
+                fn div_factor(self, factor: i128) -> Self {
+                    match <$ty as TryFrom<i128>>::try_from(factor) {
+                        Ok(factor) => self / factor,
+                        // The factor is greater in magnitude than any value of the type, except for MIN / -MIN
+                        Err(_) => self
+                            .to_i128()
+                            .ok()
+                            .and_then(|value| Self::from_i128(value / factor).ok())
+                            .expect("attempt to divide a Duration by a Period factor with overflow"),
+                    }
+                }
+
+                fn to_i128(self) -> Result<i128, DurationCastError> {
+                    <i128 as TryFrom<$ty>>::try_from(self).map_err(|_| DurationCastError::Overflow)
+                }
+
+                fn to_sign_magnitude(self) -> Result<(bool, u128), DurationCastError> {
+                    match <i128 as TryFrom<$ty>>::try_from(self) {
+                        Ok(value) => Ok((value < 0, value.unsigned_abs())),
+                        Err(_) => <u128 as TryFrom<$ty>>::try_from(self)
+                            .map(|value| (false, value))
+                            .map_err(|_| DurationCastError::Overflow),
+                    }
+                }
+
+                fn from_i128(value: i128) -> Result<Self, DurationCastError> {
+                    <$ty as TryFrom<i128>>::try_from(value).map_err(|_| {
+                        if value < 0 {
+                            DurationCastError::Underflow
+                        } else {
+                            DurationCastError::Overflow
+                        }
+                    })
+                }
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Result<Self, DurationCastError> {
+                    if negative && magnitude != 0 {
+                        if magnitude > i128::MIN.unsigned_abs() {
+                            Err(DurationCastError::Underflow)
+                        } else {
+                            Self::from_i128((magnitude as i128).wrapping_neg())
+                        }
+                    } else {
+                        <$ty as TryFrom<u128>>::try_from(magnitude).map_err(|_| DurationCastError::Overflow)
+                    }
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Result<Self, DurationCastError> {
+                    if !value.is_finite() {
+                        Err(DurationCastError::NonFinite)
+                    } else if value < (<$ty>::MIN as f64) {
+                        Err(DurationCastError::Underflow)
+                    } else if value >= (<$ty>::MAX as f64) + 1.0 {
+                        Err(DurationCastError::Overflow)
+                    } else {
+                        Ok(value as $ty)
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_duration_repr_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl DurationRepr for f32 {
+    const TREAT_AS_FLOATING_POINT: bool = true;
+
+    fn mul_factor(self, factor: i128) -> Self {
+        self * (factor as f32)
+    }
+
+    fn div_factor(self, factor: i128) -> Self {
+        self / (factor as f32)
+    }
+
+    fn to_i128(self) -> Result<i128, DurationCastError> {
+        if !self.is_finite() {
+            Err(DurationCastError::NonFinite)
+        } else if self.fract() != 0.0 {
+            Err(DurationCastError::PrecisionLoss)
+        } else if self >= (i128::MAX as f32) {
+            Err(DurationCastError::Overflow)
+        } else if self < (i128::MIN as f32) {
+            Err(DurationCastError::Underflow)
+        } else {
+            Ok(self as i128)
+        }
+    }
+
+    fn from_i128(value: i128) -> Result<Self, DurationCastError> {
+        let result = value as f32;
+        if !result.is_finite() {
+            Err(DurationCastError::Overflow)
+        } else if result.to_i128() != Ok(value) {
+            Err(DurationCastError::PrecisionLoss)
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Result<Self, DurationCastError> {
+        let result = value as f32;
+        if result.is_finite() || !value.is_finite() {
+            Ok(result)
+        } else if value < 0.0 {
+            Err(DurationCastError::Underflow)
+        } else {
+            Err(DurationCastError::Overflow)
+        }
+    }
+}
+
+impl DurationRepr for f64 {
+    const TREAT_AS_FLOATING_POINT: bool = true;
+
+    fn mul_factor(self, factor: i128) -> Self {
+        self * (factor as f64)
+    }
+
+    fn div_factor(self, factor: i128) -> Self {
+        self / (factor as f64)
+    }
+
+    fn to_i128(self) -> Result<i128, DurationCastError> {
+        if !self.is_finite() {
+            Err(DurationCastError::NonFinite)
+        } else if self.fract() != 0.0 {
+            Err(DurationCastError::PrecisionLoss)
+        } else if self >= (i128::MAX as f64) {
+            Err(DurationCastError::Overflow)
+        } else if self < (i128::MIN as f64) {
+            Err(DurationCastError::Underflow)
+        } else {
+            Ok(self as i128)
+        }
+    }
+
+    fn from_i128(value: i128) -> Result<Self, DurationCastError> {
+        let result = value as f64;
+        if !result.is_finite() {
+            Err(DurationCastError::Overflow)
+        } else if result.to_i128() != Ok(value) {
+            Err(DurationCastError::PrecisionLoss)
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Result<Self, DurationCastError> {
+        Ok(value)
+    }
 }
 
 /// A type which Represents a Duration, as a Repr value and a Period.
@@ -155,21 +403,135 @@ impl<Repr, _Period> Duration<Repr, _Period> {
     }
 }
 
-impl<R1: PartialEq<R2>, R2, _P> PartialEq<Duration<R2, _P>> for Duration<R1, _P> {
-    fn eq(&self, rhs: &Duration<R2, _P>) -> bool {
-        self.value.eq(&rhs.value)
+/// Trait for the common Repr of two Repr types, equivalent to std::common_type for the Reps of two std::chrono::durations.
+/// The common Repr is the Repr of the result of arithmetic between Durations with the Reprs Self and R2.
+///
+/// It is implemented for each Repr with itself, and between the primitive types, where the common Repr of two integer types is the narrowest type
+/// that both convert to by From, and the common Repr of an integer type and a floating-point type is the floating-point type.
+pub trait CommonRepr<R2>: DurationRepr {
+    type Output: DurationRepr;
+    /// Converts self and rhs to the common Repr
+    fn into_common(self, rhs: R2) -> (Self::Output, Self::Output);
+}
+
+impl<R: DurationRepr> CommonRepr<R> for R {
+    type Output = R;
+
+    fn into_common(self, rhs: R) -> (R, R) {
+        (self, rhs)
     }
 }
 
-impl<Repr: Eq, _P> Eq for Duration<Repr, _P> {}
+macro_rules! impl_common_repr{
+    ($($r1:ty => [$($r2:ty: $c:ty),*]);* $(;)?) => {
+        $($(
+            impl CommonRepr<$r2> for $r1 {
+                type Output = $c;
+
+                fn into_common(self, rhs: $r2) -> ($c, $c) {
+                    (self as $c, rhs as $c)
+                }
+            }
+
+            impl CommonRepr<$r1> for $r2 {
+                type Output = $c;
 
-impl<R1: PartialOrd<R2>, R2, _P> PartialOrd<Duration<R2, _P>> for Duration<R1, _P> {
-    fn partial_cmp(&self, other: &Duration<R2, _P>) -> Option<Ordering> {
-        self.value.partial_cmp(&other.value)
+                fn into_common(self, rhs: $r1) -> ($c, $c) {
+                    (self as $c, rhs as $c)
+                }
+            }
+        )*)*
     }
 }
 
-impl<R1: Ord, _P> Ord for Duration<R1, _P> {
+impl_common_repr! {
+    i8 => [i16: i16, i32: i32, i64: i64, i128: i128, isize: isize, u8: i16, u16: i32, u32: i64, u64: i128, f32: f32, f64: f64];
+    i16 => [i32: i32, i64: i64, i128: i128, isize: isize, u8: i16, u16: i32, u32: i64, u64: i128, f32: f32, f64: f64];
+    i32 => [i64: i64, i128: i128, u8: i32, u16: i32, u32: i64, u64: i128, f32: f32, f64: f64];
+    i64 => [i128: i128, u8: i64, u16: i64, u32: i64, u64: i128, f32: f32, f64: f64];
+    i128 => [u8: i128, u16: i128, u32: i128, u64: i128, f32: f32, f64: f64];
+    isize => [u8: isize, f32: f32, f64: f64];
+    u8 => [u16: u16, u32: u32, u64: u64, u128: u128, usize: usize, f32: f32, f64: f64];
+    u16 => [u32: u32, u64: u64, u128: u128, usize: usize, f32: f32, f64: f64];
+    u32 => [u64: u64, u128: u128, f32: f32, f64: f64];
+    u64 => [u128: u128, f32: f32, f64: f64];
+    u128 => [f32: f32, f64: f64];
+    usize => [f32: f32, f64: f64];
+    f32 => [f64: f64];
+}
+
+impl<R1: DurationRepr, P1: Period> Duration<R1, P1> {
+    /// Obtains the representations of self and rhs in the common Repr of R1 and R2, and the Period C, which both P1 and P2 are integer multiples of
+    fn into_common_reprs<R2, P2: Period, C: Period>(
+        self,
+        rhs: Duration<R2, P2>,
+    ) -> (
+        <R1 as CommonRepr<R2>>::Output,
+        <R1 as CommonRepr<R2>>::Output,
+    )
+    where
+        R1: CommonRepr<R2>,
+    {
+        let (lhs, rhs) = self.value.into_common(rhs.value);
+        (
+            lhs.mul_factor(RatioDivide::<P1, C>::NUMERATOR),
+            rhs.mul_factor(RatioDivide::<P2, C>::NUMERATOR),
+        )
+    }
+}
+
+/// Compares value1 * factor1 with value2 * factor2.
+/// The comparison is exact, by the signs and the 256-bit products of the magnitudes, unless either Repr treats as floating-point,
+/// in which case it is computed in f64.
+fn compare_scaled<R1: DurationRepr, R2: DurationRepr>(
+    value1: R1,
+    factor1: i128,
+    value2: R2,
+    factor2: i128,
+) -> Option<Ordering> {
+    if R1::TREAT_AS_FLOATING_POINT || R2::TREAT_AS_FLOATING_POINT {
+        return (value1.to_f64() * factor1 as f64).partial_cmp(&(value2.to_f64() * factor2 as f64));
+    }
+    let (negative1, magnitude1) = value1.to_sign_magnitude().ok()?;
+    let (negative2, magnitude2) = value2.to_sign_magnitude().ok()?;
+    let product1 = mul_wide(magnitude1, factor1.unsigned_abs());
+    let product2 = mul_wide(magnitude2, factor2.unsigned_abs());
+    let negative1 = (negative1 != (factor1 < 0)) && product1 != (0, 0);
+    let negative2 = (negative2 != (factor2 < 0)) && product2 != (0, 0);
+    Some(match (negative1, negative2) {
+        (false, false) => product1.cmp(&product2),
+        (true, true) => product2.cmp(&product1),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    })
+}
+
+/// Compares two Durations in the CommonPeriod of their Periods.
+/// Unlike the arithmetic between Durations, the comparison does not convert to the common Repr, and therefore cannot overflow.
+impl<R1: DurationRepr, R2: DurationRepr, P1: Period, P2: Period> PartialEq<Duration<R2, P2>>
+    for Duration<R1, P1>
+{
+    fn eq(&self, rhs: &Duration<R2, P2>) -> bool {
+        self.partial_cmp(rhs) == Some(Ordering::Equal)
+    }
+}
+
+impl<Repr: Eq + DurationRepr, _P: Period> Eq for Duration<Repr, _P> {}
+
+impl<R1: DurationRepr, R2: DurationRepr, P1: Period, P2: Period> PartialOrd<Duration<R2, P2>>
+    for Duration<R1, P1>
+{
+    fn partial_cmp(&self, other: &Duration<R2, P2>) -> Option<Ordering> {
+        compare_scaled(
+            self.value.clone(),
+            RatioDivide::<P1, CommonPeriod<P1, P2>>::NUMERATOR,
+            other.value.clone(),
+            RatioDivide::<P2, CommonPeriod<P1, P2>>::NUMERATOR,
+        )
+    }
+}
+
+impl<R1: Ord + DurationRepr, _P: Period> Ord for Duration<R1, _P> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.value.cmp(&other.value)
     }
@@ -181,14 +543,25 @@ impl<R1: Hash, _P> Hash for Duration<R1, _P> {
     }
 }
 
-impl<R1: Add<R2>, R2, _P> Add<Duration<R2, _P>> for Duration<R1, _P> {
-    type Output = Duration<<R1 as Add<R2>>::Output, _P>;
+/// Adds two Durations in the common Repr of their Reprs and the common Period of their Periods, equivalent to d1 + d2 for std::chrono::duration.
+/// The result is a Duration of the Ratio given by CommonPeriodWith, so that the sum of two Seconds is Seconds, and the sum of Seconds and Miliseconds is Miliseconds.
+///
+/// The conversion to the common Period overflows in the same way as the addition itself, which for the primitive integer Reprs
+/// panics in debug builds and wraps in release builds. The checked arithmetic of Duration can be used after converting to the common Period by From or DurationCast.
+impl<R1: CommonRepr<R2>, R2: DurationRepr, P1: CommonPeriodWith<P2>, P2: Period>
+    Add<Duration<R2, P2>> for Duration<R1, P1>
+where
+    <R1 as CommonRepr<R2>>::Output: Add,
+{
+    type Output = Duration<
+        <<R1 as CommonRepr<R2>>::Output as Add>::Output,
+        <P1 as CommonPeriodWith<P2>>::Output,
+    >;
 
-    fn add(self, rhs: Duration<R2, _P>) -> Self::Output {
-        Duration {
-            value: self.value + rhs.value,
-            _phantom: PhantomData,
-        }
+    fn add(self, rhs: Duration<R2, P2>) -> Self::Output {
+        let (lhs, rhs) =
+            self.into_common_reprs::<R2, P2, <P1 as CommonPeriodWith<P2>>::Output>(rhs);
+        Duration::new(lhs + rhs)
     }
 }
 
@@ -198,14 +571,20 @@ impl<R1: AddAssign<R2>, R2, _P> AddAssign<Duration<R2, _P>> for Duration<R1, _P>
     }
 }
 
-impl<R1: Sub<R2>, R2, _P> Sub<Duration<R2, _P>> for Duration<R1, _P> {
-    type Output = Duration<<R1 as Sub<R2>>::Output, _P>;
+impl<R1: CommonRepr<R2>, R2: DurationRepr, P1: CommonPeriodWith<P2>, P2: Period>
+    Sub<Duration<R2, P2>> for Duration<R1, P1>
+where
+    <R1 as CommonRepr<R2>>::Output: Sub,
+{
+    type Output = Duration<
+        <<R1 as CommonRepr<R2>>::Output as Sub>::Output,
+        <P1 as CommonPeriodWith<P2>>::Output,
+    >;
 
-    fn sub(self, rhs: Duration<R2, _P>) -> Self::Output {
-        Duration {
-            value: self.value - rhs.value,
-            _phantom: PhantomData,
-        }
+    fn sub(self, rhs: Duration<R2, P2>) -> Self::Output {
+        let (lhs, rhs) =
+            self.into_common_reprs::<R2, P2, <P1 as CommonPeriodWith<P2>>::Output>(rhs);
+        Duration::new(lhs - rhs)
     }
 }
 
@@ -215,37 +594,410 @@ impl<R1: SubAssign<R2>, R2, _P> SubAssign<Duration<R2, _P>> for Duration<R1, _P>
     }
 }
 
-impl<R1: Mul<R2>, R2, _P> Mul<R2> for Duration<R1, _P> {
-    type Output = Duration<<R1 as Mul<R2>>::Output, _P>;
+impl<R1: CommonRepr<R2>, R2: DurationRepr, P1: CommonPeriodWith<P2>, P2: Period>
+    Rem<Duration<R2, P2>> for Duration<R1, P1>
+where
+    <R1 as CommonRepr<R2>>::Output: Rem,
+{
+    type Output = Duration<
+        <<R1 as CommonRepr<R2>>::Output as Rem>::Output,
+        <P1 as CommonPeriodWith<P2>>::Output,
+    >;
+
+    fn rem(self, rhs: Duration<R2, P2>) -> Self::Output {
+        let (lhs, rhs) =
+            self.into_common_reprs::<R2, P2, <P1 as CommonPeriodWith<P2>>::Output>(rhs);
+        Duration::new(lhs % rhs)
+    }
+}
+
+impl<R1: RemAssign<R2>, R2, _P> RemAssign<Duration<R2, _P>> for Duration<R1, _P> {
+    fn rem_assign(&mut self, rhs: Duration<R2, _P>) {
+        self.value.rem_assign(rhs.value)
+    }
+}
+
+macro_rules! impl_integer_arithmetic{
+    ($($ty:ty),*) => {
+        $(
+            impl<_P> Duration<$ty, _P> {
+                /// Adds two Durations, returning None if the result overflows
+                pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                    self.value.checked_add(rhs.value).map(Duration::new)
+                }
+
+                /// Subtracts two Durations, returning None if the result overflows
+                pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    self.value.checked_sub(rhs.value).map(Duration::new)
+                }
+
+                /// Multiplies the Duration by a scalar, returning None if the result overflows
+                pub fn checked_mul(self, rhs: $ty) -> Option<Self> {
+                    self.value.checked_mul(rhs).map(Duration::new)
+                }
+
+                /// Divides the Duration by a scalar, returning None if rhs is zero or the result overflows
+                pub fn checked_div(self, rhs: $ty) -> Option<Self> {
+                    self.value.checked_div(rhs).map(Duration::new)
+                }
+
+                /// Adds two Durations, saturating at Duration::MIN and Duration::MAX
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    Duration::new(self.value.saturating_add(rhs.value))
+                }
+
+                /// Subtracts two Durations, saturating at Duration::MIN and Duration::MAX
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    Duration::new(self.value.saturating_sub(rhs.value))
+                }
+
+                /// Multiplies the Duration by a scalar, saturating at Duration::MIN and Duration::MAX
+                pub fn saturating_mul(self, rhs: $ty) -> Self {
+                    Duration::new(self.value.saturating_mul(rhs))
+                }
+
+                /// Divides the Duration by a scalar, saturating at Duration::MIN and Duration::MAX.
+                ///
+                /// Panics if rhs is zero
+                pub fn saturating_div(self, rhs: $ty) -> Self {
+                    Duration::new(self.value.saturating_div(rhs))
+                }
+
+                /// Adds two Durations, wrapping around at the bounds of the Repr
+                pub fn wrapping_add(self, rhs: Self) -> Self {
+                    Duration::new(self.value.wrapping_add(rhs.value))
+                }
+
+                /// Subtracts two Durations, wrapping around at the bounds of the Repr
+                pub fn wrapping_sub(self, rhs: Self) -> Self {
+                    Duration::new(self.value.wrapping_sub(rhs.value))
+                }
+
+                /// Multiplies the Duration by a scalar, wrapping around at the bounds of the Repr
+                pub fn wrapping_mul(self, rhs: $ty) -> Self {
+                    Duration::new(self.value.wrapping_mul(rhs))
+                }
+
+                /// Divides the Duration by a scalar, wrapping around at the bounds of the Repr.
+                ///
+                /// Panics if rhs is zero
+                pub fn wrapping_div(self, rhs: $ty) -> Self {
+                    Duration::new(self.value.wrapping_div(rhs))
+                }
+
+                /// Adds two Durations, returning the wrapped result, and whether the result overflowed
+                pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                    let (value, overflow) = self.value.overflowing_add(rhs.value);
+                    (Duration::new(value), overflow)
+                }
+
+                /// Subtracts two Durations, returning the wrapped result, and whether the result overflowed
+                pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                    let (value, overflow) = self.value.overflowing_sub(rhs.value);
+                    (Duration::new(value), overflow)
+                }
+
+                /// Multiplies the Duration by a scalar, returning the wrapped result, and whether the result overflowed
+                pub fn overflowing_mul(self, rhs: $ty) -> (Self, bool) {
+                    let (value, overflow) = self.value.overflowing_mul(rhs);
+                    (Duration::new(value), overflow)
+                }
+
+                /// Divides the Duration by a scalar, returning the wrapped result, and whether the result overflowed.
+                ///
+                /// Panics if rhs is zero
+                pub fn overflowing_div(self, rhs: $ty) -> (Self, bool) {
+                    let (value, overflow) = self.value.overflowing_div(rhs);
+                    (Duration::new(value), overflow)
+                }
+            }
+        )*
+    }
+}
+
+impl_integer_arithmetic!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_signed{
+    ($($ty:ty),*) => {
+        $(
+            impl<_P> Duration<$ty, _P> {
+                /// Computes the absolute value of the Duration.
+                ///
+                /// As with the Repr, the absolute value of Duration::MIN cannot be represented,
+                /// and will panic in debug mode. Use checked_abs instead for Durations which may be Duration::MIN.
+                pub fn abs(self) -> Self {
+                    Duration::new(self.value.abs())
+                }
+
+                /// Computes the absolute value of the Duration, returning None if self is Duration::MIN
+                pub fn checked_abs(self) -> Option<Self> {
+                    self.value.checked_abs().map(Duration::new)
+                }
+
+                /// Negates the Duration, returning None if self is Duration::MIN
+                pub fn checked_neg(self) -> Option<Self> {
+                    self.value.checked_neg().map(Duration::new)
+                }
+
+                /// Returns the sign of the Duration, that is 0 if it is zero, 1 if it is positive, and -1 if it is negative
+                pub fn signum(self) -> $ty {
+                    self.value.signum()
+                }
+
+                /// Checks if the Duration is less than zero
+                pub fn is_negative(self) -> bool {
+                    self.value.is_negative()
+                }
+
+                /// Checks if the Duration is greater than zero
+                pub fn is_positive(self) -> bool {
+                    self.value.is_positive()
+                }
+            }
+        )*
+    }
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_signed_float{
+    ($($ty:ty),*) => {
+        $(
+            impl<_P> Duration<$ty, _P> {
+                /// Computes the absolute value of the Duration
+                pub fn abs(self) -> Self {
+                    Duration::new(self.value.abs())
+                }
+
+                /// Returns the sign of the Duration, as by the signum function of the Repr
+                pub fn signum(self) -> $ty {
+                    self.value.signum()
+                }
 
-    fn mul(self, rhs: R2) -> Self::Output {
+                /// Checks if the Duration is less than zero
+                pub fn is_negative(self) -> bool {
+                    self.value < 0.0
+                }
+
+                /// Checks if the Duration is greater than zero
+                pub fn is_positive(self) -> bool {
+                    self.value > 0.0
+                }
+            }
+        )*
+    }
+}
+
+impl_signed_float!(f32, f64);
+
+impl<R: Neg, _P> Neg for Duration<R, _P> {
+    type Output = Duration<<R as Neg>::Output, _P>;
+
+    fn neg(self) -> Self::Output {
         Duration {
-            value: self.value * rhs,
+            value: -self.value,
             _phantom: PhantomData,
         }
     }
 }
 
-impl<R1: MulAssign<R2>, R2, _P> MulAssign<R2> for Duration<R1, _P> {
-    fn mul_assign(&mut self, rhs: R2) {
-        self.value.mul_assign(rhs)
+impl<R: Add<Output = R> + DurationValues, _P> Sum for Duration<R, _P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Duration::new(iter.fold(R::ZERO, |acc, d| acc + d.value))
+    }
+}
+
+impl<'a, R: Add<Output = R> + DurationValues + Clone, _P> Sum<&'a Duration<R, _P>>
+    for Duration<R, _P>
+{
+    fn sum<I: Iterator<Item = &'a Duration<R, _P>>>(iter: I) -> Self {
+        Duration::new(iter.fold(R::ZERO, |acc, d| acc + d.value.clone()))
     }
 }
 
-impl<R1: Div<R2>, R2, _P> Div<R2> for Duration<R1, _P> {
-    type Output = Duration<<R1 as Div<R2>>::Output, _P>;
+// Scaling by a Repr value is implemented for the primitive types only, so as not to overlap with the arithmetic between Durations
+macro_rules! impl_scalar_ops{
+    ($($r2:ty),*) => {
+        $(
+            impl<R1: Mul<$r2>, _P> Mul<$r2> for Duration<R1, _P> {
+                type Output = Duration<<R1 as Mul<$r2>>::Output, _P>;
 
-    fn div(self, rhs: R2) -> Self::Output {
-        Duration {
-            value: self.value / rhs,
-            _phantom: PhantomData,
+                fn mul(self, rhs: $r2) -> Self::Output {
+                    Duration {
+                        value: self.value * rhs,
+                        _phantom: PhantomData,
+                    }
+                }
+            }
+
+            impl<R1: MulAssign<$r2>, _P> MulAssign<$r2> for Duration<R1, _P> {
+                fn mul_assign(&mut self, rhs: $r2) {
+                    self.value.mul_assign(rhs)
+                }
+            }
+
+            impl<R1: Div<$r2>, _P> Div<$r2> for Duration<R1, _P> {
+                type Output = Duration<<R1 as Div<$r2>>::Output, _P>;
+
+                fn div(self, rhs: $r2) -> Self::Output {
+                    Duration {
+                        value: self.value / rhs,
+                        _phantom: PhantomData,
+                    }
+                }
+            }
+
+            impl<R1: DivAssign<$r2>, _P> DivAssign<$r2> for Duration<R1, _P> {
+                fn div_assign(&mut self, rhs: $r2) {
+                    self.value.div_assign(rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_scalar_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Divides two Durations, yielding the number of times rhs fits in self, equivalent to d1 / d2 for std::chrono::duration.
+impl<R1: CommonRepr<R2>, R2: DurationRepr, P1: Period, P2: Period> Div<Duration<R2, P2>>
+    for Duration<R1, P1>
+where
+    <R1 as CommonRepr<R2>>::Output: Div,
+{
+    type Output = <<R1 as CommonRepr<R2>>::Output as Div>::Output;
+
+    fn div(self, rhs: Duration<R2, P2>) -> Self::Output {
+        let (lhs, rhs) = self.into_common_reprs::<R2, P2, CommonPeriod<P1, P2>>(rhs);
+        lhs / rhs
+    }
+}
+
+/// Obtains the SI prefix of a period of num/den of a unit, such as "k" for 1000/1, or None if the period has no prefix.
+/// If ascii is true, "u" is used instead of "µ" for micro.
+const fn si_prefix(num: i128, den: i128, ascii: bool) -> Option<&'static str> {
+    match (num, den) {
+        (1, 1_000_000_000_000_000_000_000_000) => Some("y"),
+        (1, 1_000_000_000_000_000_000_000) => Some("z"),
+        (1, 1_000_000_000_000_000_000) => Some("a"),
+        (1, 1_000_000_000_000_000) => Some("f"),
+        (1, 1_000_000_000_000) => Some("p"),
+        (1, 1_000_000_000) => Some("n"),
+        (1, 1_000_000) if ascii => Some("u"),
+        (1, 1_000_000) => Some("µ"),
+        (1, 1_000) => Some("m"),
+        (1, 100) => Some("c"),
+        (1, 10) => Some("d"),
+        (1, 1) => Some(""),
+        (10, 1) => Some("da"),
+        (100, 1) => Some("h"),
+        (1_000, 1) => Some("k"),
+        (1_000_000, 1) => Some("M"),
+        (1_000_000_000, 1) => Some("G"),
+        (1_000_000_000_000, 1) => Some("T"),
+        (1_000_000_000_000_000, 1) => Some("P"),
+        (1_000_000_000_000_000_000, 1) => Some("E"),
+        (1_000_000_000_000_000_000_000, 1) => Some("Z"),
+        (1_000_000_000_000_000_000_000_000, 1) => Some("Y"),
+        _ => None,
+    }
+}
+
+/// Obtains the suffix used by std::chrono::duration's operator<< for minutes, hours, and days, which have no SI prefix, or None for any other period.
+const fn named_suffix(num: i128, den: i128) -> Option<&'static str> {
+    match (num, den) {
+        (60, 1) => Some("min"),
+        (3600, 1) => Some("h"),
+        (86400, 1) => Some("d"),
+        _ => None,
+    }
+}
+
+/// The suffixes of the Period P, evaluated at compile time for the Display and Debug impls of Duration and Frequency
+pub(crate) struct PeriodSuffix<P>(PhantomData<P>);
+
+impl<P: Period> PeriodSuffix<P> {
+    const NAMED: Option<&'static str> = named_suffix(P::NUMERATOR, P::DENOMINATOR);
+    const PREFIX: Option<&'static str> = si_prefix(P::NUMERATOR, P::DENOMINATOR, false);
+    const ASCII_PREFIX: Option<&'static str> = si_prefix(P::NUMERATOR, P::DENOMINATOR, true);
+
+    fn prefix(f: &Formatter) -> Option<&'static str> {
+        if f.alternate() {
+            Self::ASCII_PREFIX
+        } else {
+            Self::PREFIX
+        }
+    }
+
+    /// Writes the suffix of the Period as a Period of seconds, as by write_period_suffix
+    pub(crate) fn fmt(f: &mut Formatter) -> std::fmt::Result {
+        write_seconds_suffix(
+            f,
+            Self::NAMED,
+            Self::prefix(f),
+            P::NUMERATOR,
+            P::DENOMINATOR,
+        )
+    }
+
+    /// Writes the suffix of the Period as a Period of unit, as by write_prefixed
+    pub(crate) fn fmt_unit(f: &mut Formatter, unit: &str) -> std::fmt::Result {
+        write_prefixed(f, Self::prefix(f), unit, P::NUMERATOR, P::DENOMINATOR)
+    }
+}
+
+/// Writes the suffix of a period of num/den of unit, as its SI prefix followed by unit, or as [N]unit or [N/D]unit if the period has no SI prefix.
+fn write_prefixed(
+    f: &mut Formatter,
+    prefix: Option<&str>,
+    unit: &str,
+    num: i128,
+    den: i128,
+) -> std::fmt::Result {
+    match prefix {
+        Some(prefix) => {
+            f.write_str(prefix)?;
+            f.write_str(unit)
         }
+        None if den == 1 => write!(f, "[{}]{}", num, unit),
+        None => write!(f, "[{}/{}]{}", num, den, unit),
+    }
+}
+
+/// Writes the named suffix of a period of num/den seconds if it has one, and otherwise as by write_prefixed with the unit s.
+fn write_seconds_suffix(
+    f: &mut Formatter,
+    named: Option<&str>,
+    prefix: Option<&str>,
+    num: i128,
+    den: i128,
+) -> std::fmt::Result {
+    match named {
+        Some(named) => f.write_str(named),
+        None => write_prefixed(f, prefix, "s", num, den),
     }
 }
 
-impl<R1: DivAssign<R2>, R2, _P> DivAssign<R2> for Duration<R1, _P> {
-    fn div_assign(&mut self, rhs: R2) {
-        self.value.div_assign(rhs)
+/// Writes the suffix used by std::chrono::duration's operator<< for a period of num/den seconds that is only known at runtime,
+/// which is min, h, or d for minutes, hours, and days, and otherwise its SI prefix followed by s, or [N]s or [N/D]s if the period has no SI prefix.
+/// The alternate flag selects an ASCII only suffix.
+pub(crate) fn write_period_suffix(f: &mut Formatter, num: i128, den: i128) -> std::fmt::Result {
+    let prefix = si_prefix(num, den, f.alternate());
+    write_seconds_suffix(f, named_suffix(num, den), prefix, num, den)
+}
+
+/// Formats the Duration as its Repr value followed by the suffix of its Period, as in std::chrono::duration's operator<<.
+/// The formatting options, such as precision, apply to the Repr value, and the alternate flag selects an ASCII only suffix.
+impl<R: Display, P: Period> Display for Duration<R, P> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(&self.value, f)?;
+        PeriodSuffix::<P>::fmt(f)
+    }
+}
+
+impl<R: Debug, P: Period> Debug for Duration<R, P> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)?;
+        PeriodSuffix::<P>::fmt(f)
     }
 }
 
@@ -281,6 +1033,7 @@ impl<R, _P> sealed::Sealed for Duration<R, _P> {}
 pub trait IsDuration: sealed::Sealed {
     type Repr: Sized;
     type Period: Period;
+    #[allow(clippy::wrong_self_convention)]
     fn as_duration(self) -> Duration<Self::Repr, Self::Period>;
 }
 
@@ -322,31 +1075,330 @@ impl<R1: TryInto<R2>, R2, P: Period> DurationTryInto<R2> for Duration<R1, P> {
     }
 }
 
+/// The factor from P1 to P2, as used to convert a value of Repr.
+///
+/// The From impls between the named Periods include pairs, such as Yotta and Yocto, with no factor that fits in i128.
+/// The factor depends on Repr, so that it is a compile-time error only when such a conversion is instantiated.
+struct PeriodFactor<Repr, P1, P2>(PhantomData<Repr>, PhantomData<P1>, PhantomData<P2>);
+
+impl<Repr: DurationRepr, P1: Period, P2: Period> PeriodFactor<Repr, P1, P2> {
+    const FACTOR: (i128, i128) = match (Repr::MAX_FACTOR, checked_ratio_divide::<P1, P2>()) {
+        (_, Some(factor)) => factor,
+        (_, None) => panic!("the factor between the Periods overflows i128"),
+    };
+}
+
+/// Converts a value of Repr in P1 to a value of Repr in P2
+pub(crate) fn convert_period<Repr: DurationRepr, P1: Period, P2: Period>(value: Repr) -> Repr {
+    value
+        .mul_factor(PeriodFactor::<Repr, P1, P2>::FACTOR.0)
+        .div_factor(PeriodFactor::<Repr, P1, P2>::FACTOR.1)
+}
+
+struct AssertFactorFits<Repr, P1, P2>(PhantomData<Repr>, PhantomData<P1>, PhantomData<P2>);
+
+impl<Repr: DurationRepr, P1: Period, P2: Period> AssertFactorFits<Repr, P1, P2> {
+    const OK: () = assert!(
+        PeriodFactor::<Repr, P1, P2>::FACTOR.0 <= Repr::MAX_FACTOR,
+        "Lossless Duration conversion requires the factor between the Periods to fit in the target Repr"
+    );
+}
+
+// Equivalent to the implicit conversions of std::chrono::duration:
+// From is implemented between Durations when the conversion of Repr is lossless, and either the target Period evenly divides the source Period,
+// or the target Repr implements TreatAsFloatingPoint.
+// Between integer Reprs, the target Period must be DividesEvenly of the source Period, and the factor between the Periods must fit in the target Repr,
+// which is checked when the conversion is instantiated, as it cannot be expressed as a bound.
+// As From<T> is implemented for T, conversions between Periods of the same Repr are only provided between the named Periods.
+// Other Lossy or Unsupported conversions must use DurationCast
+
+/// Trait for Repr types which convert losslessly from a Duration with the Period P1 to a Duration with the Period P2,
+/// which is all Periods for Reprs that implement TreatAsFloatingPoint, and Periods where P2 is DividesEvenly of P1 for the primitive integer types.
+///
+/// ```compile_fail
+/// # use cxx_chrono::{duration::*, prelude::*};
+/// let _ = Seconds::from(Miliseconds::new(1500));
+/// ```
+pub trait LosslessPeriodConversion<P1: Period, P2: Period>: DurationRepr {}
+
+impl<R: DurationRepr + TreatAsFloatingPoint, P1: Period, P2: Period>
+    LosslessPeriodConversion<P1, P2> for R
+{
+}
+
+macro_rules! impl_lossless_period_conversion{
+    ($($ty:ty),*) => {
+        $(
+            impl<P1: Period, P2: DividesEvenly<P1>> LosslessPeriodConversion<P1, P2> for $ty {}
+        )*
+    }
+}
+
+impl_lossless_period_conversion!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_from_int_repr{
+    ($($r1:ty => [$($r2:ty),*]);* $(;)?) => {
+        $($(
+            impl<P1: Period, P2: DividesEvenly<P1>> From<Duration<$r1, P1>> for Duration<$r2, P2> {
+                fn from(d: Duration<$r1, P1>) -> Self {
+                    let () = AssertFactorFits::<$r2, P1, P2>::OK;
+                    Duration::new(convert_period::<$r2, P1, P2>(<$r2 as From<$r1>>::from(d.value)))
+                }
+            }
+        )*)*
+    }
+}
+
+impl_from_int_repr! {
+    i8 => [i16, i32, i64, i128, isize];
+    i16 => [i32, i64, i128, isize];
+    i32 => [i64, i128];
+    i64 => [i128];
+    u8 => [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize];
+    u16 => [u32, u64, u128, usize, i32, i64, i128];
+    u32 => [u64, u128, i64, i128];
+    u64 => [u128, i128];
+}
+
+macro_rules! impl_from_float_repr{
+    ($($r1:ty => [$($r2:ty),*]);* $(;)?) => {
+        $($(
+            impl<P1: Period, P2: Period> From<Duration<$r1, P1>> for Duration<$r2, P2> {
+                fn from(d: Duration<$r1, P1>) -> Self {
+                    Duration::new(convert_period::<$r2, P1, P2>(d.value as $r2))
+                }
+            }
+        )*)*
+    }
+}
+
+impl_from_float_repr! {
+    i8 => [f32, f64];
+    i16 => [f32, f64];
+    i32 => [f32, f64];
+    i64 => [f32, f64];
+    i128 => [f32, f64];
+    isize => [f32, f64];
+    u8 => [f32, f64];
+    u16 => [f32, f64];
+    u32 => [f32, f64];
+    u64 => [f32, f64];
+    u128 => [f32, f64];
+    usize => [f32, f64];
+    f32 => [f64];
+}
+
+macro_rules! impl_from_period{
+    () => {};
+    ($p1:ty $(, $p2:ty)* $(,)?) => {
+        $(
+            impl<Repr: LosslessPeriodConversion<$p1, $p2>> From<Duration<Repr, $p1>> for Duration<Repr, $p2> {
+                fn from(d: Duration<Repr, $p1>) -> Self {
+                    let () = AssertFactorFits::<Repr, $p1, $p2>::OK;
+                    Duration::new(convert_period::<Repr, $p1, $p2>(d.value))
+                }
+            }
+
+            impl<Repr: LosslessPeriodConversion<$p2, $p1>> From<Duration<Repr, $p2>> for Duration<Repr, $p1> {
+                fn from(d: Duration<Repr, $p2>) -> Self {
+                    let () = AssertFactorFits::<Repr, $p2, $p1>::OK;
+                    Duration::new(convert_period::<Repr, $p2, $p1>(d.value))
+                }
+            }
+        )*
+        impl_from_period!($($p2),*);
+    }
+}
+
+named_periods!(impl_from_period);
+
+/// The Error returned by DurationCast, when the source Duration cannot be converted to the target Duration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DurationCastError {
+    /// The converted value is greater than the maximum value of the target Repr
+    Overflow,
+    /// The converted value is less than the minimum value of the target Repr
+    Underflow,
+    /// The source value is NaN or infinite
+    NonFinite,
+    /// The source value, or the converted value, cannot be represented exactly by the intermediate or target Repr
+    PrecisionLoss,
+}
+
+impl Display for DurationCastError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            DurationCastError::Overflow => {
+                f.write_str("duration overflowed the target representation")
+            }
+            DurationCastError::Underflow => {
+                f.write_str("duration underflowed the target representation")
+            }
+            DurationCastError::NonFinite => f.write_str("duration is not finite"),
+            DurationCastError::PrecisionLoss => {
+                f.write_str("duration cannot be represented without loss of precision")
+            }
+        }
+    }
+}
+
+impl Error for DurationCastError {}
+
+/// Trait for explicit conversions between Durations, equivalent to std::chrono::duration_cast, and the std::chrono::floor, ceil, and round functions for durations.
+/// If either Repr treats as floating-point, the conversion is computed in f64, and duration_cast does not truncate a floating-point result.
 pub trait DurationCast<D: IsDuration>: IsDuration {
     type Error;
+    /// Converts to D, truncating towards zero
     fn duration_cast(self) -> Result<D, <Self as DurationCast<D>>::Error>;
+    /// Converts to D, rounding towards negative infinity
+    fn duration_floor(self) -> Result<D, <Self as DurationCast<D>>::Error>;
+    /// Converts to D, rounding towards positive infinity
+    fn duration_ceil(self) -> Result<D, <Self as DurationCast<D>>::Error>;
+    /// Converts to D, rounding to the nearest value of D, and to even in the case of a tie
+    fn duration_round(self) -> Result<D, <Self as DurationCast<D>>::Error>;
 }
 
-impl<R1, P1: Period, R2, P2: Period> DurationCast<Duration<R2, P2>> for Duration<R1, P1>
-where
-    R1: Mul<i64>,
-    <R1 as Mul<i64>>::Output: Div<i64, Output = R1::Output>,
-    R1::Output: TryInto<R2>,
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Rounding {
+    TowardZero,
+    Floor,
+    Ceil,
+    NearestEven,
+    Exact,
+}
+
+/// Computes value * num / den, where value is the sign and magnitude (negative, m), rounded according to mode, where den is positive.
+/// The result is a sign and a magnitude.
+///
+/// The magnitude of the product is computed in 256 bits when it overflows u128, so that this only overflows when the result does.
+fn mul_div_rounded(
+    (negative, m): (bool, u128),
+    num: i128,
+    den: i128,
+    mode: Rounding,
+) -> Result<(bool, u128), DurationCastError> {
+    let negative = negative != (num < 0);
+    let overflow = if negative {
+        DurationCastError::Underflow
+    } else {
+        DurationCastError::Overflow
+    };
+    let (n, d) = (num.unsigned_abs(), den as u128);
+    let (q, r) = match m.checked_mul(n) {
+        Some(p) => (p / d, p % d),
+        None => {
+            let (hi, lo) = mul_wide(m, n);
+            div_wide(hi, lo, d).ok_or(overflow)?
+        }
+    };
+    // Rounding the magnitude away from zero rounds a negative result towards negative infinity
+    let away = match mode {
+        Rounding::Exact if r != 0 => return Err(DurationCastError::PrecisionLoss),
+        Rounding::Floor => negative && r != 0,
+        Rounding::Ceil => !negative && r != 0,
+        Rounding::NearestEven => r > d - r || (r == d - r && q % 2 != 0),
+        _ => false,
+    };
+    let q = q.checked_add(away as u128).ok_or(overflow)?;
+    Ok((negative, q))
+}
+
+/// Converts value * num / den from R1 to R2, rounded according to mode, where den is positive.
+/// If either Repr treats as floating-point, the conversion is computed in f64, as by DurationCast.
+pub(crate) fn rescale<R1: DurationRepr, R2: DurationRepr>(
+    value: R1,
+    num: i128,
+    den: i128,
+    mode: Rounding,
+) -> Result<R2, DurationCastError> {
+    if R1::TREAT_AS_FLOATING_POINT || R2::TREAT_AS_FLOATING_POINT {
+        let value = value.to_f64();
+        if !value.is_finite() {
+            return Err(DurationCastError::NonFinite);
+        }
+        let value = value * (num as f64) / (den as f64);
+        let value = match mode {
+            // duration_cast only truncates when the target is integral
+            Rounding::TowardZero if R2::TREAT_AS_FLOATING_POINT => value,
+            Rounding::TowardZero => value.trunc(),
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+            Rounding::NearestEven => value.round_ties_even(),
+            Rounding::Exact if R2::TREAT_AS_FLOATING_POINT || value.fract() == 0.0 => value,
+            Rounding::Exact => return Err(DurationCastError::PrecisionLoss),
+        };
+        R2::from_f64(value)
+    } else {
+        let (negative, magnitude) = mul_div_rounded(value.to_sign_magnitude()?, num, den, mode)?;
+        R2::from_sign_magnitude(negative, magnitude)
+    }
+}
+
+impl<R1: DurationRepr, P1: Period, R2: DurationRepr, P2: Period> DurationCast<Duration<R2, P2>>
+    for Duration<R1, P1>
 {
-    type Error = <Duration<<R1 as Mul<i64>>::Output, P2> as DurationTryInto<R2>>::Error;
+    type Error = DurationCastError;
+
+    fn duration_cast(self) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_rounded(Rounding::TowardZero)
+    }
+
+    fn duration_floor(self) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_rounded(Rounding::Floor)
+    }
+
+    fn duration_ceil(self) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_rounded(Rounding::Ceil)
+    }
+
+    fn duration_round(self) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_rounded(Rounding::NearestEven)
+    }
+}
 
-    fn duration_cast(
+impl<R1: DurationRepr, P1: Period> Duration<R1, P1> {
+    fn cast_rounded<R2: DurationRepr, P2: Period>(
         self,
-    ) -> Result<Duration<R2, P2>, <Self as DurationCast<Duration<R2, P2>>>::Error> {
-        let (num, denom) = (
+        mode: Rounding,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        rescale(
+            self.value,
             RatioDivide::<P1, P2>::NUMERATOR,
             RatioDivide::<P1, P2>::DENOMINATOR,
-        );
-        let value = (self.value.mul(num)).div(denom);
-        DurationTryInto::<R2>::try_into(Duration {
-            value,
-            _phantom: PhantomData,
-        })
+            mode,
+        )
+        .map(Duration::new)
+    }
+
+    /// Converts to a Duration<R2, P2>, failing with DurationCastError::PrecisionLoss if the result is not exact
+    pub(crate) fn cast_exact<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_rounded(Rounding::Exact)
+    }
+}
+
+/// Converts a std::time::Duration to a Duration, failing if the result overflows, or if the std::time::Duration cannot be represented exactly.
+impl<R: DurationRepr, P: Period> TryFrom<StdDuration> for Duration<R, P> {
+    type Error = DurationCastError;
+
+    fn try_from(d: StdDuration) -> Result<Self, DurationCastError> {
+        // at most u64::MAX seconds, which is well within the range of i128 nanoseconds
+        Nanoseconds::new(d.as_nanos() as i128).cast_exact()
+    }
+}
+
+/// Converts a Duration to a std::time::Duration, failing if the Duration is negative, overflows, or cannot be represented exactly.
+impl<R: DurationRepr, P: Period> TryFrom<Duration<R, P>> for StdDuration {
+    type Error = DurationCastError;
+
+    fn try_from(d: Duration<R, P>) -> Result<Self, DurationCastError> {
+        let nanos = d.cast_exact::<i128, Nano>()?.into_inner();
+        if nanos < 0 {
+            return Err(DurationCastError::Underflow);
+        }
+        let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| DurationCastError::Overflow)?;
+        Ok(StdDuration::new(secs, (nanos % 1_000_000_000) as u32))
     }
 }
 
@@ -365,7 +1417,9 @@ unsafe impl<R1: Step, P1> Step for Duration<R1, P1> {
     }
 }
 
+/// The average length of a Gregorian year. See crate::calendar::CalendarArithmetic for calendrical arithmetic with years.
 pub type Years = Duration<i64, Ratio<31556952, 1>>;
+/// The average length of a Gregorian month. See crate::calendar::CalendarArithmetic for calendrical arithmetic with months.
 pub type Months = Duration<i64, Ratio<2629746, 1>>;
 pub type Weeks = Duration<i64, Ratio<604800, 1>>;
 pub type Days = Duration<i64, Ratio<86400, 1>>;
@@ -0,0 +1,143 @@
+use crate::{
+    duration::{rescale, write_period_suffix, Rounding},
+    prelude::*,
+    ratio::DynRatio,
+};
+
+use std::fmt::{Debug, Display, Formatter};
+
+/// A Duration with a Period that is only known at runtime, such as a number of ticks of a hardware counter with a frequency read from a device tree,
+/// or a timestamp in the timebase of a media container.
+///
+/// A DynDuration is converted to a Duration<R, P> by duration_cast, duration_floor, duration_ceil, and duration_round, and to another runtime Period by
+/// cast_to, floor_to, ceil_to, and round_to, with the same rounding rules as DurationCast. A Duration<R, P> is converted From exactly, keeping its Period.
+/// As the factor between the Periods is only known at runtime, a conversion fails with DurationCastError::Overflow if the factor does not fit in a DynRatio,
+/// or if the target Period is zero.
+#[derive(Copy, Clone)]
+pub struct DynDuration<R> {
+    value: R,
+    period: DynRatio,
+}
+
+impl<R> DynDuration<R> {
+    pub const fn new(value: R, period: DynRatio) -> Self {
+        Self { value, period }
+    }
+
+    /// Obtains the representation of the DynDuration value, in units of its Period
+    pub fn into_inner(self) -> R {
+        self.value
+    }
+
+    pub fn period(&self) -> DynRatio {
+        self.period
+    }
+}
+
+impl<R1: DurationRepr> DynDuration<R1> {
+    fn cast_rounded<R2: DurationRepr>(
+        self,
+        period: DynRatio,
+        mode: Rounding,
+    ) -> Result<DynDuration<R2>, DurationCastError> {
+        let factor = self
+            .period
+            .checked_div(period)
+            .ok_or(DurationCastError::Overflow)?;
+        rescale(self.value, factor.numerator(), factor.denominator(), mode)
+            .map(|value| DynDuration::new(value, period))
+    }
+
+    /// Converts to a DynDuration with the given Period, truncating towards zero
+    pub fn cast_to<R2: DurationRepr>(
+        self,
+        period: DynRatio,
+    ) -> Result<DynDuration<R2>, DurationCastError> {
+        self.cast_rounded(period, Rounding::TowardZero)
+    }
+
+    /// Converts to a DynDuration with the given Period, rounding towards negative infinity
+    pub fn floor_to<R2: DurationRepr>(
+        self,
+        period: DynRatio,
+    ) -> Result<DynDuration<R2>, DurationCastError> {
+        self.cast_rounded(period, Rounding::Floor)
+    }
+
+    /// Converts to a DynDuration with the given Period, rounding towards positive infinity
+    pub fn ceil_to<R2: DurationRepr>(
+        self,
+        period: DynRatio,
+    ) -> Result<DynDuration<R2>, DurationCastError> {
+        self.cast_rounded(period, Rounding::Ceil)
+    }
+
+    /// Converts to a DynDuration with the given Period, rounding to the nearest value, and to even in the case of a tie
+    pub fn round_to<R2: DurationRepr>(
+        self,
+        period: DynRatio,
+    ) -> Result<DynDuration<R2>, DurationCastError> {
+        self.cast_rounded(period, Rounding::NearestEven)
+    }
+
+    /// Converts to a Duration<R2, P2>, truncating towards zero
+    pub fn duration_cast<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_to(DynRatio::of::<P2>())
+            .map(|d| Duration::new(d.value))
+    }
+
+    /// Converts to a Duration<R2, P2>, rounding towards negative infinity
+    pub fn duration_floor<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.floor_to(DynRatio::of::<P2>())
+            .map(|d| Duration::new(d.value))
+    }
+
+    /// Converts to a Duration<R2, P2>, rounding towards positive infinity
+    pub fn duration_ceil<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.ceil_to(DynRatio::of::<P2>())
+            .map(|d| Duration::new(d.value))
+    }
+
+    /// Converts to a Duration<R2, P2>, rounding to the nearest value of Duration<R2, P2>, and to even in the case of a tie
+    pub fn duration_round<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.round_to(DynRatio::of::<P2>())
+            .map(|d| Duration::new(d.value))
+    }
+
+    /// Converts to a Duration<R2, P2>, failing with DurationCastError::PrecisionLoss if the result is not exact
+    pub(crate) fn duration_exact<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Duration<R2, P2>, DurationCastError> {
+        self.cast_rounded(DynRatio::of::<P2>(), Rounding::Exact)
+            .map(|d| Duration::new(d.value))
+    }
+}
+
+impl<R, P: Period> From<Duration<R, P>> for DynDuration<R> {
+    fn from(d: Duration<R, P>) -> Self {
+        Self::new(d.into_inner(), DynRatio::of::<P>())
+    }
+}
+
+/// Formats the DynDuration as its Repr value followed by the suffix of its Period, as by the Display impl of Duration
+impl<R: Display> Display for DynDuration<R> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(&self.value, f)?;
+        write_period_suffix(f, self.period.numerator(), self.period.denominator())
+    }
+}
+
+impl<R: Debug> Debug for DynDuration<R> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)?;
+        write_period_suffix(f, self.period.numerator(), self.period.denominator())
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    duration::{convert_period, PeriodSuffix},
+    prelude::*,
+};
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+/// A type which Represents a Frequency, as a Repr value and a Period of hertz, such that Frequency<i64, Kilo> is a number of kilohertz.
+///
+/// Frequency is the reciprocal of Duration: a Duration multiplied by a Frequency is a number of cycles, a number of cycles divided by a Duration is a Frequency,
+/// and a Repr value divided by a Frequency is a Duration, such as 1.0 / Frequency::<f64>::new(50.0), which is the period of one cycle.
+/// The Period of the results is the Reciprocal of the Period of the operand, so that, for example, 1.0 / Frequency::<f64>::new(50.0) is
+/// a Duration<f64, Reciprocal<Unit>> of 0.02 seconds, which can be converted to any other Duration by DurationCast.
+/// As with the other arithmetic of integer Durations, the quotient of integer Reprs is truncated towards zero, so that 48000 / Seconds::new(1) is exactly
+/// Hertz::new(48000), but 1 / Hertz::new(50) is zero seconds. The period of an integer Frequency can be computed exactly by frequency_cast to a floating point Repr first.
+///
+/// Frequencies are compared and converted between Periods in the same way as Durations.
+#[repr(transparent)]
+pub struct Frequency<Repr, Period = Unit> {
+    value: Repr,
+    _phantom: PhantomData<*mut Period>,
+}
+// Because the use of PhantomData<*mut Period> suppressed the auto impls.
+unsafe impl<Repr: Send, Period> Send for Frequency<Repr, Period> {}
+unsafe impl<Repr: Sync, Period> Sync for Frequency<Repr, Period> {}
+
+impl<Repr, _Period> Frequency<Repr, _Period> {
+    /// Constructs a new Frequency from its representation.
+    pub const fn new(x: Repr) -> Self {
+        Self {
+            value: x,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Obtains the representation of the Frequency value
+    pub fn into_inner(self) -> Repr {
+        self.value
+    }
+
+    /// Converts the Frequency to a Duration with the same Repr and Period, which has the same arithmetic between Periods
+    fn into_duration(self) -> Duration<Repr, _Period> {
+        Duration::new(self.value)
+    }
+}
+
+impl<R1: DurationRepr, P1: Period> Frequency<R1, P1> {
+    /// Converts to a Frequency<R2, P2>, truncating towards zero, as by DurationCast::duration_cast
+    pub fn frequency_cast<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Frequency<R2, P2>, DurationCastError> {
+        DurationCast::<Duration<R2, P2>>::duration_cast(self.into_duration())
+            .map(|d| Frequency::new(d.into_inner()))
+    }
+
+    /// Converts to a Frequency<R2, P2>, rounding towards negative infinity, as by DurationCast::duration_floor
+    pub fn frequency_floor<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Frequency<R2, P2>, DurationCastError> {
+        DurationCast::<Duration<R2, P2>>::duration_floor(self.into_duration())
+            .map(|d| Frequency::new(d.into_inner()))
+    }
+
+    /// Converts to a Frequency<R2, P2>, rounding towards positive infinity, as by DurationCast::duration_ceil
+    pub fn frequency_ceil<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Frequency<R2, P2>, DurationCastError> {
+        DurationCast::<Duration<R2, P2>>::duration_ceil(self.into_duration())
+            .map(|d| Frequency::new(d.into_inner()))
+    }
+
+    /// Converts to a Frequency<R2, P2>, rounding to the nearest value, and to even in the case of a tie, as by DurationCast::duration_round
+    pub fn frequency_round<R2: DurationRepr, P2: Period>(
+        self,
+    ) -> Result<Frequency<R2, P2>, DurationCastError> {
+        DurationCast::<Duration<R2, P2>>::duration_round(self.into_duration())
+            .map(|d| Frequency::new(d.into_inner()))
+    }
+}
+
+impl<R1: DurationRepr, R2: DurationRepr, P1: Period, P2: Period> PartialEq<Frequency<R2, P2>>
+    for Frequency<R1, P1>
+{
+    fn eq(&self, rhs: &Frequency<R2, P2>) -> bool {
+        self.clone().into_duration() == rhs.clone().into_duration()
+    }
+}
+
+impl<R: Eq + DurationRepr, P: Period> Eq for Frequency<R, P> {}
+
+impl<R1: DurationRepr, R2: DurationRepr, P1: Period, P2: Period> PartialOrd<Frequency<R2, P2>>
+    for Frequency<R1, P1>
+{
+    fn partial_cmp(&self, rhs: &Frequency<R2, P2>) -> Option<Ordering> {
+        self.clone()
+            .into_duration()
+            .partial_cmp(&rhs.clone().into_duration())
+    }
+}
+
+impl<R: Ord + DurationRepr, P: Period> Ord for Frequency<R, P> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.value.cmp(&rhs.value)
+    }
+}
+
+impl<R: Hash, P> Hash for Frequency<R, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<R: Clone, P> Clone for Frequency<R, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<R: Copy, P> Copy for Frequency<R, P> {}
+
+impl<R: Default, P> Default for Frequency<R, P> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+/// Multiplies a Duration by a Frequency in the common Repr of their Reprs, yielding the number of cycles in the Duration, truncated towards zero.
+impl<R1: CommonRepr<R2>, R2: DurationRepr, P1: Period, P2: Period> Mul<Frequency<R2, P2>>
+    for Duration<R1, P1>
+where
+    <R1 as CommonRepr<R2>>::Output: Mul<Output = <R1 as CommonRepr<R2>>::Output>,
+{
+    type Output = <R1 as CommonRepr<R2>>::Output;
+
+    fn mul(self, rhs: Frequency<R2, P2>) -> Self::Output {
+        let (lhs, rhs) = self.into_inner().into_common(rhs.value);
+        convert_period::<_, RatioMultiply<P1, P2>, Unit>(lhs * rhs)
+    }
+}
+
+/// Multiplies a Frequency by a Duration in the common Repr of their Reprs, yielding the number of cycles in the Duration, truncated towards zero.
+impl<R1: CommonRepr<R2>, R2: DurationRepr, P1: Period, P2: Period> Mul<Duration<R2, P2>>
+    for Frequency<R1, P1>
+where
+    <R1 as CommonRepr<R2>>::Output: Mul<Output = <R1 as CommonRepr<R2>>::Output>,
+{
+    type Output = <R1 as CommonRepr<R2>>::Output;
+
+    fn mul(self, rhs: Duration<R2, P2>) -> Self::Output {
+        let (lhs, rhs) = self.value.into_common(rhs.into_inner());
+        convert_period::<_, RatioMultiply<P1, P2>, Unit>(lhs * rhs)
+    }
+}
+
+// Dividing a Repr value is implemented for the primitive types only, as a generic impl would not be permitted for a foreign Repr type.
+// As with the other arithmetic of integer Durations, the quotient of integer Reprs is truncated towards zero
+macro_rules! impl_reciprocal_ops{
+    ($($r:ty),*) => {
+        $(
+            /// Divides a number of cycles by a Duration, yielding the Frequency of the cycles
+            impl<P: Period> Div<Duration<$r, P>> for $r {
+                type Output = Frequency<$r, Reciprocal<P>>;
+
+                fn div(self, rhs: Duration<$r, P>) -> Self::Output {
+                    Frequency::new(self / rhs.into_inner())
+                }
+            }
+
+            /// Divides a number of cycles by a Frequency, yielding the Duration of the cycles
+            impl<P: Period> Div<Frequency<$r, P>> for $r {
+                type Output = Duration<$r, Reciprocal<P>>;
+
+                fn div(self, rhs: Frequency<$r, P>) -> Self::Output {
+                    Duration::new(self / rhs.value)
+                }
+            }
+        )*
+    }
+}
+
+impl_reciprocal_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Formats the Frequency as its Repr value followed by the SI prefix of its Period and Hz, such as 50Hz or 19200kHz, or [N/D]Hz if the Period has no SI prefix.
+/// The formatting options, such as precision, apply to the Repr value, and the alternate flag selects an ASCII only suffix.
+impl<R: Display, P: Period> Display for Frequency<R, P> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(&self.value, f)?;
+        PeriodSuffix::<P>::fmt_unit(f, "Hz")
+    }
+}
+
+impl<R: Debug, P: Period> Debug for Frequency<R, P> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)?;
+        PeriodSuffix::<P>::fmt_unit(f, "Hz")
+    }
+}
+
+pub type Hertz = Frequency<i64, Unit>;
+pub type Kilohertz = Frequency<i64, Kilo>;
+pub type Megahertz = Frequency<i64, Mega>;
+pub type Gigahertz = Frequency<i64, Giga>;
@@ -0,0 +1,163 @@
+use crate::{parse::ParseDurationError, prelude::*};
+
+use std::fmt::{Display, Formatter};
+
+/// Formats a Duration as by Go's time.Duration.String, such as 1h2m3.456789s, 1.5µs, 100ns, or -3m0s.
+///
+/// Durations of at least one second are written in hours, minutes, and seconds, omitting leading zero units,
+/// and shorter Durations are written in the largest unit of s, ms, µs, and ns which is not greater than the Duration.
+/// The Duration is first truncated to nanoseconds, but unlike Go, it may exceed the range of an int64 number of nanoseconds.
+/// Durations which cannot be written, as they are not finite, or overflow an i128 number of nanoseconds, are rejected by Go::new.
+pub struct Go<D>(D);
+
+impl<R: DurationRepr, P: Period> Go<Duration<R, P>> {
+    /// Wraps a Duration to be formatted, failing if it is not finite, or if it overflows an i128 number of nanoseconds
+    pub fn new(d: Duration<R, P>) -> Result<Self, DurationCastError> {
+        DurationCast::<Nanoseconds>::duration_cast(d.clone())?;
+        Ok(Self(d))
+    }
+}
+
+impl<D> Go<D> {
+    /// Obtains the wrapped Duration
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+/// Writes v / 10^prec, omitting trailing zeros of the fraction, and the decimal point if the fraction is zero
+fn write_frac(f: &mut Formatter, v: u128, prec: u32) -> std::fmt::Result {
+    let scale = 10u128.pow(prec);
+    write!(f, "{}", v / scale)?;
+    let frac = v % scale;
+    if frac != 0 {
+        let digits = format!("{:0width$}", frac, width = prec as usize);
+        write!(f, ".{}", digits.trim_end_matches('0'))?;
+    }
+    Ok(())
+}
+
+impl<R: DurationRepr, P: Period> Display for Go<Duration<R, P>> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let nanos = match DurationCast::<Nanoseconds>::duration_cast(self.0.clone()) {
+            Ok(nanos) => nanos.into_inner(),
+            Err(_) => unreachable!("Go::new rejects Durations which overflow Nanoseconds"),
+        };
+        let u = nanos.unsigned_abs();
+        if nanos < 0 {
+            f.write_str("-")?;
+        }
+        match u {
+            0 => f.write_str("0s"),
+            u if u < 1_000 => write!(f, "{}ns", u),
+            u if u < 1_000_000 => {
+                write_frac(f, u, 3)?;
+                f.write_str("µs")
+            }
+            u if u < 1_000_000_000 => {
+                write_frac(f, u, 6)?;
+                f.write_str("ms")
+            }
+            u => {
+                // Stop at hours, because days can be different lengths
+                let (seconds, minutes, hours) =
+                    (u / 1_000_000_000, u / 60_000_000_000, u / 3_600_000_000_000);
+                if hours > 0 {
+                    write!(f, "{}h", hours)?;
+                }
+                if minutes > 0 {
+                    write!(f, "{}m", minutes % 60)?;
+                }
+                write_frac(f, (seconds % 60) * 1_000_000_000 + u % 1_000_000_000, 9)?;
+                f.write_str("s")
+            }
+        }
+    }
+}
+
+/// Parses a Duration as by Go's time.ParseDuration, such as 300ms, -1.5h, or 2h45m.
+///
+/// The string is a possibly signed sequence of decimal numbers, each with an optional fraction and a unit suffix,
+/// where the units are ns, us (or µs), ms, s, m, and h. No whitespace is permitted.
+/// As in Go, fractions of a nanosecond are truncated, and the result must be in the range of an int64 number of nanoseconds.
+/// The result is then converted to the requested Duration, failing if it is not exact, or if it overflows the Repr.
+pub fn parse_go<R: DurationRepr, P: Period>(s: &str) -> Result<Duration<R, P>, ParseDurationError> {
+    let (negative, mut rest) = match s.chars().next() {
+        Some('-') => (true, &s[1..]),
+        Some('+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if rest == "0" {
+        return Ok(Nanoseconds::new(0).cast_exact()?);
+    } else if rest.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    // Go permits a magnitude of up to 1 << 63, which is only in range when negative
+    let limit = if negative {
+        1i128 << 63
+    } else {
+        (1i128 << 63) - 1
+    };
+    let overflow = if negative {
+        DurationCastError::Underflow
+    } else {
+        DurationCastError::Overflow
+    };
+    let mut total = 0i128;
+    while !rest.is_empty() {
+        let int_len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (integral, after) = rest.split_at(int_len);
+        let (fraction, after) = match after.strip_prefix('.') {
+            Some(after) => {
+                let frac_len = after
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(after.len());
+                after.split_at(frac_len)
+            }
+            None => ("", after),
+        };
+        if integral.is_empty() && fraction.is_empty() {
+            return Err(ParseDurationError::Invalid);
+        }
+        let unit_len = after
+            .find(|c: char| c == '.' || c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_len);
+        let unit: i128 = match unit {
+            "" => return Err(ParseDurationError::Invalid),
+            "ns" => 1,
+            "us" | "µs" | "μs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            _ => return Err(ParseDurationError::UnknownUnit),
+        };
+        let mut value = 0i128;
+        for c in integral.bytes() {
+            value = value * 10 + (c - b'0') as i128;
+            if value > limit {
+                return Err(overflow.into());
+            }
+        }
+        value *= unit;
+        // As in Go, digits of the fraction are ignored once they would overflow an int64
+        let (mut num, mut den) = (0i128, 1i128);
+        for c in fraction.bytes() {
+            if num * 10 + ((c - b'0') as i128) >= (1i128 << 63) {
+                break;
+            }
+            num = num * 10 + (c - b'0') as i128;
+            den *= 10;
+        }
+        total += value + num * unit / den;
+        if total > limit {
+            return Err(overflow.into());
+        }
+        rest = after;
+    }
+    let total = if negative { -total } else { total };
+    Ok(Nanoseconds::new(total).cast_exact()?)
+}
@@ -0,0 +1,177 @@
+use crate::{
+    duration::{Hours, IsDuration, Minutes, Seconds},
+    prelude::*,
+};
+
+use std::{
+    convert::TryFrom,
+    fmt::{Debug, Display, Formatter},
+};
+
+/// The Duration type used for the subseconds of a HhMmSs<D>, which can exactly represent the fractional seconds of D
+pub type Precision<D> = Duration<i128, SubsecondPrecision<<D as IsDuration>::Period>>;
+
+/// A Duration split into hours, minutes, seconds, and subseconds, equivalent to std::chrono::hh_mm_ss.
+///
+/// The subseconds are expressed in Precision<D>, which has the least number of decimal digits, up to 24, needed to represent the fractional seconds of D exactly,
+/// or 6 digits if there is no such number.
+/// Durations which do not fit in Precision<D>, such as floating-point Durations, are truncated towards zero.
+pub struct HhMmSs<D: IsDuration> {
+    negative: bool,
+    hours: Hours,
+    minutes: Minutes,
+    seconds: Seconds,
+    subseconds: Precision<D>,
+}
+
+impl<R: DurationRepr, P: Period> HhMmSs<Duration<R, P>> {
+    /// The number of fractional digits of the subseconds, equivalent to hh_mm_ss::fractional_width
+    pub const FRACTIONAL_WIDTH: u32 = SubsecondPrecision::<P>::WIDTH;
+
+    /// Splits d into hours, minutes, seconds, and subseconds, failing if the number of hours overflows an i64, or if d is not finite.
+    pub fn new(d: Duration<R, P>) -> Result<Self, DurationCastError> {
+        let total: Precision<Duration<R, P>> = DurationCast::duration_cast(d)?;
+        let total = total.into_inner();
+        let negative = total < 0;
+        let magnitude = total.unsigned_abs();
+        let den = SubsecondPrecision::<P>::DENOMINATOR as u128;
+        let (seconds, subseconds) = (magnitude / den, magnitude % den);
+        let hours = i64::try_from(seconds / 3600).map_err(|_| {
+            if negative {
+                DurationCastError::Underflow
+            } else {
+                DurationCastError::Overflow
+            }
+        })?;
+        Ok(Self {
+            negative,
+            hours: Duration::new(hours),
+            minutes: Duration::new(((seconds / 60) % 60) as i64),
+            seconds: Duration::new((seconds % 60) as i64),
+            subseconds: Duration::new(subseconds as i128),
+        })
+    }
+
+    /// Checks if the Duration is negative. The components are the magnitude of the Duration.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Obtains the number of whole hours in the magnitude of the Duration
+    pub fn hours(&self) -> Hours {
+        self.hours
+    }
+
+    /// Obtains the number of whole minutes in the magnitude of the Duration, modulo 60
+    pub fn minutes(&self) -> Minutes {
+        self.minutes
+    }
+
+    /// Obtains the number of whole seconds in the magnitude of the Duration, modulo 60
+    pub fn seconds(&self) -> Seconds {
+        self.seconds
+    }
+
+    /// Obtains the fractional seconds of the magnitude of the Duration
+    pub fn subseconds(&self) -> Precision<Duration<R, P>> {
+        self.subseconds
+    }
+
+    /// Recombines the components into a Duration of Precision<D>
+    pub fn to_duration(&self) -> Precision<Duration<R, P>> {
+        let den = SubsecondPrecision::<P>::DENOMINATOR as u128;
+        let seconds = (self.hours.into_inner() as u128) * 3600
+            + (self.minutes.into_inner() as u128) * 60
+            + self.seconds.into_inner() as u128;
+        // The magnitude is at most that of the original i128 value, which is 2^127 if it was i128::MIN
+        let magnitude = (seconds * den + self.subseconds.into_inner() as u128) as i128;
+        Duration::new(if self.negative {
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        })
+    }
+}
+
+impl<D: IsDuration> Clone for HhMmSs<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: IsDuration> Copy for HhMmSs<D> {}
+
+impl<D: IsDuration> PartialEq for HhMmSs<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.negative == other.negative
+            && self.hours == other.hours
+            && self.minutes == other.minutes
+            && self.seconds == other.seconds
+            && self.subseconds == other.subseconds
+    }
+}
+
+impl<D: IsDuration> Eq for HhMmSs<D> {}
+
+/// Formats as HH:MM:SS, followed by a decimal point and FRACTIONAL_WIDTH digits of subseconds unless FRACTIONAL_WIDTH is 0, such as 01:02:03.250 for Miliseconds.
+/// The hours are written with at least two digits, and a negative Duration is prefixed with a minus sign.
+impl<R: DurationRepr, P: Period> Display for HhMmSs<Duration<R, P>> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hours.into_inner(),
+            self.minutes.into_inner(),
+            self.seconds.into_inner()
+        )?;
+        let width = Self::FRACTIONAL_WIDTH as usize;
+        if width != 0 {
+            write!(
+                f,
+                ".{:0width$}",
+                self.subseconds.into_inner(),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<R: DurationRepr, P: Period> Debug for HhMmSs<Duration<R, P>> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "HhMmSs({})", self)
+    }
+}
+
+/// Checks if h, as a time of day, is before noon, that is, if it is in the range [0h, 11h]
+pub fn is_am(h: Hours) -> bool {
+    (0..12).contains(&h.into_inner())
+}
+
+/// Checks if h, as a time of day, is at or after noon, that is, if it is in the range [12h, 23h]
+pub fn is_pm(h: Hours) -> bool {
+    (12..24).contains(&h.into_inner())
+}
+
+/// Converts h from a 24-hour time in the range [0h, 23h] to a 12-hour time in the range [1h, 12h].
+/// Other values are returned unchanged.
+pub fn make12(h: Hours) -> Hours {
+    match h.into_inner() {
+        0 => Duration::new(12),
+        h @ 13..=23 => Duration::new(h - 12),
+        h => Duration::new(h),
+    }
+}
+
+/// Converts h from a 12-hour time in the range [1h, 12h] to a 24-hour time in the range [0h, 23h], in the afternoon if pm is true.
+/// Other values are returned unchanged.
+pub fn make24(h: Hours, pm: bool) -> Hours {
+    match (h.into_inner(), pm) {
+        (12, false) => Duration::new(0),
+        (h @ 1..=11, true) => Duration::new(h + 12),
+        (h, _) => Duration::new(h),
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    parse::{split_decimal, DecimalSum, ParseDurationError},
+    prelude::*,
+    ratio::{div_wide, mul_wide},
+};
+
+use std::fmt::{Display, Formatter};
+
+/// Formats a Duration as an ISO 8601 duration, such as PT1H30M, P3DT4H, PT0.25S, or -PT5S.
+///
+/// The Duration is written in days, hours, minutes, and seconds, where a day is exactly 86400 seconds.
+/// Fractional seconds are written with as many digits as required, up to 24 digits, and floating-point Durations are first rounded to nanoseconds.
+/// Durations which cannot be written, as they are not finite, or their number of seconds overflows u128, are rejected by Iso8601::new.
+pub struct Iso8601<D>(D);
+
+impl<R: DurationRepr, P: Period> Iso8601<Duration<R, P>> {
+    /// Wraps a Duration to be formatted, failing if it is not finite, or if its number of seconds overflows u128
+    pub fn new(d: Duration<R, P>) -> Result<Self, DurationCastError> {
+        decompose(&d)?;
+        Ok(Self(d))
+    }
+}
+
+impl<D> Iso8601<D> {
+    /// Obtains the wrapped Duration
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+/// Splits a Duration into its sign, its whole number of seconds, and up to 24 digits of its fractional seconds
+fn decompose<R: DurationRepr, P: Period>(
+    d: &Duration<R, P>,
+) -> Result<(bool, u128, String), DurationCastError> {
+    let (value, num, den) = if R::TREAT_AS_FLOATING_POINT {
+        let nanos: Nanoseconds = DurationCast::duration_round(d.clone())?;
+        (nanos.into_inner(), 1, 1_000_000_000)
+    } else {
+        let value = d.clone().into_inner().to_i128()?;
+        (value, P::NUMERATOR, P::DENOMINATOR)
+    };
+    let negative = (value < 0) != (num < 0);
+    let overflow = if negative {
+        DurationCastError::Underflow
+    } else {
+        DurationCastError::Overflow
+    };
+    let (num, den) = (num.unsigned_abs(), den as u128);
+    let (hi, lo) = mul_wide(value.unsigned_abs(), num);
+    let (seconds, mut remainder) = div_wide(hi, lo, den).ok_or(overflow)?;
+    let mut fraction = String::new();
+    while remainder != 0 && fraction.len() < 24 {
+        // remainder < den, so the digit is less than 10
+        let (hi, lo) = mul_wide(remainder, 10);
+        let (digit, r) = div_wide(hi, lo, den).ok_or(overflow)?;
+        fraction.push((b'0' + digit as u8) as char);
+        remainder = r;
+    }
+    let len = fraction.trim_end_matches('0').len();
+    fraction.truncate(len);
+    Ok((negative && (seconds != 0 || len != 0), seconds, fraction))
+}
+
+impl<R: DurationRepr, P: Period> Display for Iso8601<Duration<R, P>> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (negative, seconds, fraction) = match decompose(&self.0) {
+            Ok(parts) => parts,
+            Err(_) => unreachable!("Iso8601::new rejects Durations which cannot be decomposed"),
+        };
+        if negative {
+            f.write_str("-")?;
+        }
+        f.write_str("P")?;
+        let days = seconds / 86400;
+        let has_time = seconds % 86400 != 0 || !fraction.is_empty();
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+        if has_time || days == 0 {
+            write_time(f, "", seconds % 86400, &fraction)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the time part of an ISO 8601 duration, from the T designator onwards, with each component prefixed by sign.
+/// Zero components are omitted, unless all components are zero, in which case 0S is written.
+pub(crate) fn write_time(
+    f: &mut Formatter,
+    sign: &str,
+    seconds: u128,
+    fraction: &str,
+) -> std::fmt::Result {
+    let (hours, minutes, seconds) = (seconds / 3600, (seconds / 60) % 60, seconds % 60);
+    f.write_str("T")?;
+    if hours != 0 {
+        write!(f, "{}{}H", sign, hours)?;
+    }
+    if minutes != 0 {
+        write!(f, "{}{}M", sign, minutes)?;
+    }
+    // PT0S is written for a zero Duration
+    if seconds != 0 || !fraction.is_empty() || (hours == 0 && minutes == 0) {
+        write!(f, "{}{}", sign, seconds)?;
+        if !fraction.is_empty() {
+            write!(f, ".{}", fraction)?;
+        }
+        f.write_str("S")?;
+    }
+    Ok(())
+}
+
+/// Parses an ISO 8601 duration, such as PT1H30M, P3DT4H, P1W, PT0.5S, or -PT5S, as an exact Duration.
+///
+/// Weeks and days are exactly 604800 and 86400 seconds respectively.
+/// Year and month components are rejected with ParseDurationError::CalendarComponent, as they do not have an exact length.
+/// Either a period or a comma may be used as the decimal separator, and only the last component may have a fraction.
+pub fn parse_iso8601<R: DurationRepr, P: Period>(
+    s: &str,
+) -> Result<Duration<R, P>, ParseDurationError> {
+    parse(s, false)
+}
+
+/// Parses an ISO 8601 duration, as by parse_iso8601, but accepting year and month components,
+/// using the average lengths of the Years and Months Durations.
+pub fn parse_iso8601_average<R: DurationRepr, P: Period>(
+    s: &str,
+) -> Result<Duration<R, P>, ParseDurationError> {
+    parse(s, true)
+}
+
+fn parse<R: DurationRepr, P: Period>(
+    s: &str,
+    allow_average: bool,
+) -> Result<Duration<R, P>, ParseDurationError> {
+    let (negative, rest) = match s.chars().next() {
+        Some('-') => (true, &s[1..]),
+        Some('+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let mut rest = rest.strip_prefix('P').ok_or(ParseDurationError::Invalid)?;
+    if rest.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    // The designators, in the order they must appear, and their lengths in seconds
+    let date_units: [(char, i128); 4] =
+        [('Y', 31556952), ('M', 2629746), ('W', 604800), ('D', 86400)];
+    let time_units: [(char, i128); 3] = [('H', 3600), ('M', 60), ('S', 1)];
+    let mut total = DecimalSum::default();
+    let mut components = 0;
+    let mut fractional = false;
+    let mut time = false;
+    let mut units = &date_units[..];
+    while !rest.is_empty() {
+        if !time && rest.starts_with('T') {
+            time = true;
+            units = &time_units[..];
+            rest = &rest[1..];
+            if rest.is_empty() {
+                return Err(ParseDurationError::Invalid);
+            }
+            continue;
+        }
+        if fractional {
+            return Err(ParseDurationError::Invalid);
+        }
+        let (number, after) = split_decimal(rest, true).ok_or(ParseDurationError::Invalid)?;
+        let designator = after.chars().next().ok_or(ParseDurationError::Invalid)?;
+        let pos = units
+            .iter()
+            .position(|&(c, _)| c == designator)
+            .ok_or(ParseDurationError::Invalid)?;
+        if !time && (designator == 'Y' || designator == 'M') && !allow_average {
+            return Err(ParseDurationError::CalendarComponent);
+        }
+        total
+            .add(&number, units[pos].1, 0, R::TREAT_AS_FLOATING_POINT)
+            .map_err(|e| match e {
+                DurationCastError::Overflow if negative => DurationCastError::Underflow,
+                e => e,
+            })?;
+        fractional = !number.fraction.is_empty();
+        components += 1;
+        units = &units[pos + 1..];
+        rest = &after[designator.len_utf8()..];
+    }
+    if components == 0 {
+        return Err(ParseDurationError::Empty);
+    }
+    Ok(total.into_duration(negative)?)
+}
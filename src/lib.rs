@@ -1,8 +1,16 @@
 #![cfg_attr(feature = "step", feature(step_trait, step_trait_ext))]
 #![deny(warnings)]
 
+pub mod calendar;
+pub mod calendar_duration;
 pub mod clock;
 pub mod duration;
+pub mod dyn_duration;
+pub mod frequency;
+pub mod go;
+pub mod hh_mm_ss;
+pub mod iso8601;
+pub mod parse;
 pub mod prelude;
 pub mod ratio;
 pub mod time_point;
@@ -0,0 +1,212 @@
+use crate::{dyn_duration::DynDuration, prelude::*};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The Error returned when parsing a Duration from a string fails
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseDurationError {
+    /// The string contains no components
+    Empty,
+    /// The string is not a sequence of number and unit components
+    Invalid,
+    /// A component has a unit which is not recognized
+    UnknownUnit,
+    /// A year or month component, which does not have an exact length, was not allowed
+    CalendarComponent,
+    /// The parsed value cannot be represented exactly by the Duration
+    Cast(DurationCastError),
+}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseDurationError::Empty => f.write_str("empty duration string"),
+            ParseDurationError::Invalid => f.write_str("invalid duration string"),
+            ParseDurationError::UnknownUnit => f.write_str("unknown unit in duration string"),
+            ParseDurationError::CalendarComponent => {
+                f.write_str("year or month component in exact duration string")
+            }
+            ParseDurationError::Cast(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for ParseDurationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDurationError::Cast(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DurationCastError> for ParseDurationError {
+    fn from(e: DurationCastError) -> Self {
+        ParseDurationError::Cast(e)
+    }
+}
+
+/// A decimal number, split into its integral and fractional digits
+pub(crate) struct Decimal<'a> {
+    pub(crate) integral: &'a str,
+    pub(crate) fraction: &'a str,
+}
+
+/// Splits a leading decimal number, with at least one digit and an optional fraction, from s.
+/// If allow_comma is true, a comma is also accepted as the decimal separator.
+pub(crate) fn split_decimal(s: &str, allow_comma: bool) -> Option<(Decimal<'_>, &str)> {
+    let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let int_len = digits(s);
+    let (integral, rest) = s.split_at(int_len);
+    let (fraction, rest) = match rest.chars().next() {
+        Some('.') => {
+            let frac_len = digits(&rest[1..]);
+            (&rest[1..1 + frac_len], &rest[1 + frac_len..])
+        }
+        Some(',') if allow_comma => {
+            let frac_len = digits(&rest[1..]);
+            (&rest[1..1 + frac_len], &rest[1 + frac_len..])
+        }
+        _ => ("", rest),
+    };
+    if integral.is_empty() && fraction.is_empty() {
+        None
+    } else {
+        Some((Decimal { integral, fraction }, rest))
+    }
+}
+
+/// The greatest number of decimal digits of a fraction of a second which a DecimalSum can hold, as 10^38 is the greatest power of 10 which fits in i128
+const MAX_EXPONENT: u32 = 38;
+
+/// An exact sum of decimal numbers of units, as a number of 1/10^exponent seconds,
+/// which is converted to the requested Duration once all components have been added.
+#[derive(Default)]
+pub(crate) struct DecimalSum {
+    value: i128,
+    exponent: u32,
+}
+
+impl DecimalSum {
+    /// Adds number * unit / 10^unit_exponent seconds to the sum.
+    /// Digits of the fraction beyond the precision of the sum fail with PrecisionLoss, unless lossy is true, in which case they are truncated.
+    pub(crate) fn add(
+        &mut self,
+        number: &Decimal,
+        unit: i128,
+        unit_exponent: u32,
+        lossy: bool,
+    ) -> Result<(), DurationCastError> {
+        let mut fraction = number.fraction.trim_end_matches('0');
+        let max_len = (MAX_EXPONENT - unit_exponent) as usize;
+        if fraction.len() > max_len {
+            if !lossy {
+                return Err(DurationCastError::PrecisionLoss);
+            }
+            fraction = &fraction[..max_len];
+        }
+        let mut value = 0i128;
+        for c in number.integral.bytes().chain(fraction.bytes()) {
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((c - b'0') as i128))
+                .ok_or(DurationCastError::Overflow)?;
+        }
+        let exponent = fraction.len() as u32 + unit_exponent;
+        let target = exponent.max(self.exponent);
+        let value = value
+            .checked_mul(unit)
+            .and_then(|v| v.checked_mul(10i128.pow(target - exponent)));
+        let sum = self.value.checked_mul(10i128.pow(target - self.exponent));
+        self.value = value
+            .zip(sum)
+            .and_then(|(value, sum)| sum.checked_add(value))
+            .ok_or(DurationCastError::Overflow)?;
+        self.exponent = target;
+        Ok(())
+    }
+
+    /// Converts the sum, negated if negative is true, to a Duration, failing if it cannot be represented exactly, as by DurationCast
+    pub(crate) fn into_duration<R: DurationRepr, P: Period>(
+        self,
+        negative: bool,
+    ) -> Result<Duration<R, P>, DurationCastError> {
+        let value = if negative { -self.value } else { self.value };
+        let period = DynRatio::new(1, 10i128.pow(self.exponent)).expect("10^exponent is not zero");
+        DynDuration::new(value, period).duration_exact()
+    }
+}
+
+/// Obtains the length of a unit, as a multiple of 1/10^exponent seconds
+fn unit_length(unit: &str) -> Option<(i128, u32)> {
+    match unit {
+        "ns" => Some((1, 9)),
+        "us" | "µs" | "μs" => Some((1, 6)),
+        "ms" => Some((1, 3)),
+        "s" => Some((1, 0)),
+        "m" | "min" => Some((60, 0)),
+        "h" => Some((3600, 0)),
+        "d" => Some((86400, 0)),
+        "w" => Some((604800, 0)),
+        _ => None,
+    }
+}
+
+/// Parses a Duration from a string of number and unit components, such as 1h30m, 250ms, 1.5s, or 3d 12h.
+///
+/// Each component is a decimal number, optionally with a fraction, followed by one of the units
+/// ns, us (or µs), ms, s, m (or min), h, d, or w. Components may be separated by whitespace, and the string may begin with a sign.
+/// A string consisting only of 0 is also accepted.
+///
+/// The sum of the components is converted to the requested Duration, failing if it is not exact, or if it overflows the Repr.
+/// The components are summed exactly, so a string is only rejected as inexact if it is more precise than the Period, unless the Repr treats as floating-point.
+impl<R: DurationRepr, P: Period> FromStr for Duration<R, P> {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, ParseDurationError> {
+        let s = s.trim();
+        let (negative, mut rest) = match s.chars().next() {
+            Some('-') => (true, &s[1..]),
+            Some('+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if rest.is_empty() {
+            return Err(ParseDurationError::Empty);
+        } else if rest == "0" {
+            return Ok(Nanoseconds::new(0).cast_exact()?);
+        }
+        let mut total = DecimalSum::default();
+        while !rest.is_empty() {
+            let (number, after) =
+                split_decimal(rest.trim_start(), false).ok_or(ParseDurationError::Invalid)?;
+            let after = after.trim_start();
+            let unit_len = after
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(after.len());
+            let (unit, after) = after.split_at(unit_len);
+            if unit.is_empty() {
+                return Err(ParseDurationError::Invalid);
+            }
+            let (unit, exponent) = unit_length(unit).ok_or(ParseDurationError::UnknownUnit)?;
+            total
+                .add(&number, unit, exponent, R::TREAT_AS_FLOATING_POINT)
+                .map_err(|e| match e {
+                    DurationCastError::Overflow if negative => DurationCastError::Underflow,
+                    e => e,
+                })?;
+            rest = after.trim_start();
+        }
+        Ok(total.into_duration(negative)?)
+    }
+}
+
+/// Parses a Duration from a string of number and unit components, as by the FromStr implementation of Duration.
+pub fn parse_duration<D: FromStr<Err = ParseDurationError>>(
+    s: &str,
+) -> Result<D, ParseDurationError> {
+    s.parse()
+}
@@ -1,10 +1,13 @@
-pub use crate::clock::{
-    Clock, TrivialClock, Monotonic,
-    SystemClock, SteadyClock
+pub use crate::clock::{Clock, Monotonic, SteadyClock, SystemClock, TrivialClock};
+pub use crate::duration::{
+    CommonRepr, Duration, DurationCast, DurationCastError, DurationRepr, DurationValues,
+    IsDuration, Miliseconds, Nanoseconds, TreatAsFloatingPoint,
 };
-pub use crate::duration::{Duration, TreatAsFloatingPoint, DurationCast};
+pub use crate::frequency::Frequency;
 pub use crate::ratio::{
-    Giga, Kilo, Mega, Micro, Mili, Nano, Period, Pico, Ratio, RatioDivide, RatioMultiply,
-    Reciprocal, Tera, Unit,
+    Atto, Centi, CommonPeriod, CommonPeriodWith, Deca, Deci, DividesEvenly, DynRatio, Exa, Femto,
+    Giga, Hecto, Kilo, Mega, Micro, Mili, Nano, Period, Peta, Pico, Ratio, RatioAdd, RatioDivide,
+    RatioGcd, RatioLcm, RatioMultiply, RatioSubtract, Reciprocal, SubsecondPrecision, Tera, Unit,
+    Yocto, Yotta, Zepto, Zetta,
 };
 pub use crate::time_point::TimePoint;
@@ -1,7 +1,11 @@
-use std::marker::PhantomData;
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+};
 
 #[derive(Copy, Clone)]
-pub struct Ratio<const N: i64, const D: i64>;
+pub struct Ratio<const N: i128, const D: i128>;
 
 #[doc(hidden)]
 mod sealed {
@@ -9,11 +13,11 @@ mod sealed {
 }
 
 pub trait Period: sealed::Sealed {
-    const NUMERATOR: i64;
-    const DENOMINATOR: i64;
+    const NUMERATOR: i128;
+    const DENOMINATOR: i128;
 }
 
-const fn gcd(a: i64, b: i64) -> i64 {
+const fn gcd(a: u128, b: u128) -> u128 {
     if b == 0 {
         a
     } else {
@@ -21,102 +25,648 @@ const fn gcd(a: i64, b: i64) -> i64 {
     }
 }
 
-const fn gcd128(a: i128, b: i128) -> i128 {
-    if b == 0 {
-        a
+/// The lcm of a and b, or None if it overflows u128
+const fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        Some(0)
     } else {
-        gcd128(b, a % b)
+        (a / gcd(a, b)).checked_mul(b)
     }
 }
 
-const fn signum(a: i64) -> i64 {
-    match a {
-        n if n < 0 => -1,
-        0 => 0,
-        _ => 1,
+/// Computes the full 256-bit product of a and b, as the high and low 128 bits
+pub(crate) const fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    // At most 3 * (2^64 - 1), which cannot overflow
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    (
+        p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64),
+        (p00 & MASK) | (mid << 64),
+    )
+}
+
+/// Divides the 256-bit value hi * 2^128 + lo by den, returning the quotient and remainder, or None if the quotient overflows u128
+pub(crate) fn div_wide(hi: u128, lo: u128, den: u128) -> Option<(u128, u128)> {
+    if hi >= den {
+        return None;
+    }
+    // Binary long division, where the remainder is always less than den before shifting
+    let (mut q, mut r) = (0u128, hi);
+    for i in (0..128).rev() {
+        let carry = r >> 127;
+        r = (r << 1) | ((lo >> i) & 1);
+        q <<= 1;
+        if carry != 0 || r >= den {
+            r = r.wrapping_sub(den);
+            q |= 1;
+        }
+    }
+    Some((q, r))
+}
+
+/// Reduces the magnitudes num/den to lowest terms, where den is nonzero, and applies the sign, or returns None if the result does not fit in i128
+const fn checked_normalize(negative: bool, num: u128, den: u128) -> Option<(i128, i128)> {
+    let g = gcd(num, den);
+    let (num, den) = (num / g, den / g);
+    if den > i128::MAX as u128
+        || (num > i128::MAX as u128 && !(negative && num == i128::MIN.unsigned_abs()))
+    {
+        None
+    } else if negative {
+        Some(((num as i128).wrapping_neg(), den as i128))
+    } else {
+        Some((num as i128, den as i128))
     }
 }
 
-impl<const N: i64, const D: i64> From<Ratio<N, D>> for f64 {
+// Option::expect is not const before Rust 1.83
+macro_rules! expect_period {
+    ($e:expr) => {
+        match $e {
+            Some(v) => v,
+            None => panic!("Period overflows i128"),
+        }
+    };
+}
+
+/// Reduces the magnitudes num/den to lowest terms, and applies the sign.
+///
+/// Panics if den is zero, or if the result does not fit in i128.
+/// As this is only called in the initializers of the Period constants, this is a post-monomorphisation error rather than a runtime panic.
+const fn normalize(negative: bool, num: u128, den: u128) -> (i128, i128) {
+    assert!(den != 0, "Period has a zero denominator");
+    expect_period!(checked_normalize(negative, num, den))
+}
+
+/// Reduces num/den to lowest terms with a positive denominator
+const fn reduce(num: i128, den: i128) -> (i128, i128) {
+    normalize(
+        (num < 0) != (den < 0),
+        num.unsigned_abs(),
+        den.unsigned_abs(),
+    )
+}
+
+impl<const N: i128, const D: i128> From<Ratio<N, D>> for f64 {
     fn from(_: Ratio<N, D>) -> f64 {
         (Ratio::<N, D>::NUMERATOR as f64) / (Ratio::<N, D>::DENOMINATOR as f64)
     }
 }
 
-impl<const N: i64, const D: i64> sealed::Sealed for Ratio<N, D> {}
+impl<const N: i128, const D: i128> sealed::Sealed for Ratio<N, D> {}
 
-impl<const N: i64, const D: i64> Period for Ratio<N, D> {
-    const NUMERATOR: i64 = signum(D) * N / gcd(N, D);
-    const DENOMINATOR: i64 = signum(D) * D / gcd(N, D);
+/// The Period of Ratio<N, D> is N/D in lowest terms, with a positive Denominator.
+/// It is a compile-time error to use the Period of a Ratio with a zero Denominator.
+///
+/// ```compile_fail
+/// # use cxx_chrono::ratio::*;
+/// let _ = <Ratio<1, 0> as Period>::NUMERATOR;
+/// ```
+impl<const N: i128, const D: i128> Period for Ratio<N, D> {
+    const NUMERATOR: i128 = reduce(N, D).0;
+    const DENOMINATOR: i128 = reduce(N, D).1;
 }
 
-const fn ratio_divide(r1: (i64, i64), r2: (i64, i64)) -> (i64, i64) {
-    let num = (r1.0 as i128) * (r2.1 as i128);
-    let denom = (r2.0 as i128) * (r1.1 as i128);
-    (
-        (num / gcd128(num, denom)) as i64,
-        (denom / gcd128(num, denom)) as i64,
-    )
+const fn ratio_divide(r1: (i128, i128), r2: (i128, i128)) -> (i128, i128) {
+    assert!(r2.0 != 0, "division by a zero Period");
+    ratio_multiply(r1, (r2.1, r2.0))
 }
 
-const fn ratio_multiply(r1: (i64, i64), r2: (i64, i64)) -> (i64, i64) {
-    let num = (r1.0 as i128) * (r1.1 as i128);
-    let denom = (r2.0 as i128) * (r2.1 as i128);
-    (
-        (num / gcd128(num, denom)) as i64,
-        (denom / gcd128(num, denom)) as i64,
+const fn ratio_multiply(r1: (i128, i128), r2: (i128, i128)) -> (i128, i128) {
+    expect_period!(checked_multiply(r1, r2))
+}
+
+/// Divides the Period A by the Period B, or returns None if B is zero or the quotient does not fit in i128.
+/// Unlike RatioDivide, this is not a compile-time error.
+pub(crate) const fn checked_ratio_divide<A: Period, B: Period>() -> Option<(i128, i128)> {
+    if B::NUMERATOR == 0 {
+        None
+    } else {
+        checked_multiply(
+            (A::NUMERATOR, A::DENOMINATOR),
+            (B::DENOMINATOR, B::NUMERATOR),
+        )
+    }
+}
+
+/// Multiplies two ratios with nonzero denominators, or returns None if the product does not fit in i128.
+///
+/// Cancels the common factors of each Numerator with the other Denominator before multiplying,
+/// so that only a product which does not fit in lowest terms overflows
+const fn checked_multiply(r1: (i128, i128), r2: (i128, i128)) -> Option<(i128, i128)> {
+    let negative = ((r1.0 < 0) != (r1.1 < 0)) != ((r2.0 < 0) != (r2.1 < 0));
+    let (n1, d1) = (r1.0.unsigned_abs(), r1.1.unsigned_abs());
+    let (n2, d2) = (r2.0.unsigned_abs(), r2.1.unsigned_abs());
+    let (g1, g2) = (gcd(n1, d2), gcd(n2, d1));
+    match (
+        (n1 / g1).checked_mul(n2 / g2),
+        (d1 / g2).checked_mul(d2 / g1),
+    ) {
+        (Some(num), Some(den)) => checked_normalize(negative, num, den),
+        _ => None,
+    }
+}
+
+/// Compares two ratios with positive denominators, by their signs, and then by the 256-bit products of their magnitudes
+const fn less(r1: (i128, i128), r2: (i128, i128)) -> bool {
+    let (s1, s2) = (r1.0.signum(), r2.0.signum());
+    if s1 != s2 || s1 == 0 {
+        return s1 < s2;
+    }
+    let (l_hi, l_lo) = mul_wide(r1.0.unsigned_abs(), r2.1 as u128);
+    let (r_hi, r_lo) = mul_wide(r2.0.unsigned_abs(), r1.1 as u128);
+    if s1 > 0 {
+        l_hi < r_hi || (l_hi == r_hi && l_lo < r_lo)
+    } else {
+        l_hi > r_hi || (l_hi == r_hi && l_lo > r_lo)
+    }
+}
+
+/// The gcd of the Numerators over the lcm of the Denominators, which are positive
+const fn common_period(r1: (i128, i128), r2: (i128, i128)) -> (i128, i128) {
+    normalize(
+        false,
+        gcd(r1.0.unsigned_abs(), r2.0.unsigned_abs()),
+        expect_period!(lcm(r1.1 as u128, r2.1 as u128)),
     )
 }
 
+/// The product of the Periods A and B.
+/// It is a compile-time error to use the Period if the product overflows i128.
+///
+/// ```compile_fail
+/// # use cxx_chrono::ratio::*;
+/// let _ = <RatioMultiply<Yotta, Yotta> as Period>::NUMERATOR;
+/// ```
 pub struct RatioMultiply<A, B>(PhantomData<A>, PhantomData<B>);
 
 impl<A: sealed::Sealed, B: sealed::Sealed> sealed::Sealed for RatioMultiply<A, B> {}
 
 impl<A: Period, B: Period> Period for RatioMultiply<A, B> {
-    const NUMERATOR: i64 = ratio_multiply(
+    const NUMERATOR: i128 = ratio_multiply(
         (A::NUMERATOR, A::DENOMINATOR),
         (B::NUMERATOR, B::DENOMINATOR),
     )
     .0;
-    const DENOMINATOR: i64 = ratio_multiply(
+    const DENOMINATOR: i128 = ratio_multiply(
         (A::NUMERATOR, A::DENOMINATOR),
         (B::NUMERATOR, B::DENOMINATOR),
     )
     .1;
 }
 
+/// The quotient of the Periods A and B.
+/// It is a compile-time error to use the Period if the quotient overflows i128, or if B is zero.
+///
+/// ```compile_fail
+/// # use cxx_chrono::ratio::*;
+/// let _ = <RatioDivide<Yocto, Yotta> as Period>::DENOMINATOR;
+/// ```
 pub struct RatioDivide<A, B>(PhantomData<A>, PhantomData<B>);
 
 impl<A: sealed::Sealed, B: sealed::Sealed> sealed::Sealed for RatioDivide<A, B> {}
 
 impl<A: Period, B: Period> Period for RatioDivide<A, B> {
-    const NUMERATOR: i64 = ratio_divide(
+    const NUMERATOR: i128 = ratio_divide(
         (A::NUMERATOR, A::DENOMINATOR),
         (B::NUMERATOR, B::DENOMINATOR),
     )
     .0;
-    const DENOMINATOR: i64 = ratio_divide(
+    const DENOMINATOR: i128 = ratio_divide(
         (A::NUMERATOR, A::DENOMINATOR),
         (B::NUMERATOR, B::DENOMINATOR),
     )
     .1;
 }
 
+/// The reciprocal of the Period R.
+/// It is a compile-time error to use the Period if R is zero.
+///
+/// ```compile_fail
+/// # use cxx_chrono::ratio::*;
+/// let _ = <Reciprocal<Ratio<0, 1>> as Period>::DENOMINATOR;
+/// ```
 pub struct Reciprocal<R>(PhantomData<R>);
 
 impl<R: sealed::Sealed> sealed::Sealed for Reciprocal<R> {}
 
 impl<R: Period> Period for Reciprocal<R> {
-    const NUMERATOR: i64 = R::DENOMINATOR;
-    const DENOMINATOR: i64 = R::NUMERATOR;
+    const NUMERATOR: i128 = ratio_divide((1, 1), (R::NUMERATOR, R::DENOMINATOR)).0;
+    const DENOMINATOR: i128 = ratio_divide((1, 1), (R::NUMERATOR, R::DENOMINATOR)).1;
+}
+
+/// The common Period of two Periods, equivalent to the period of std::common_type for two std::chrono::duration specializations.
+/// The Numerator is the gcd of the Numerators of A and B, and the Denominator is the lcm of the Denominators of A and B,
+/// thus both A and B are integer multiples of CommonPeriod<A, B>.
+/// It is a compile-time error to use the Period if the lcm overflows i128.
+pub struct CommonPeriod<A, B>(PhantomData<A>, PhantomData<B>);
+
+impl<A: sealed::Sealed, B: sealed::Sealed> sealed::Sealed for CommonPeriod<A, B> {}
+
+impl<A: Period, B: Period> Period for CommonPeriod<A, B> {
+    const NUMERATOR: i128 = common_period(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .0;
+    const DENOMINATOR: i128 = common_period(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .1;
+}
+
+/// Invokes the macro $m with the named Periods, which are the SI Periods and the Periods of the Duration aliases,
+/// so that the impls between the named Periods are generated from the same list.
+macro_rules! named_periods {
+    ($m:ident) => {
+        $m! {
+            Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca,
+            Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>,
+            Unit, Deci, Centi, Mili, Micro, Nano, Pico, Femto, Atto, Zepto, Yocto
+        }
+    };
+}
+
+pub(crate) use named_periods;
+
+/// Trait for the common Period of Self and B as a Ratio, which is the Period of the result of arithmetic between Durations with the Periods Self and B.
+///
+/// It is implemented for each Period with itself, and between each pair of the named Periods, that is the SI Periods and the Periods of the Duration aliases,
+/// where the Output is the Ratio with the value of CommonPeriod<Self, B>, such as Mili for Unit and Mili, or Ratio<60, 1> for Ratio<3600, 1> and Ratio<86400, 1>.
+/// The arithmetic between Durations of other Periods must convert to a common Period first, such as by DurationCast.
+pub trait CommonPeriodWith<B: Period>: Period {
+    type Output: Period;
+}
+
+impl<P: Period> CommonPeriodWith<P> for P {
+    type Output = P;
+}
+
+macro_rules! impl_common_period_with{
+    () => {};
+    ($a:ty $(, $b:ty)* $(,)?) => {
+        $(
+            impl CommonPeriodWith<$b> for $a {
+                type Output = Ratio<
+                    { <CommonPeriod<$a, $b> as Period>::NUMERATOR },
+                    { <CommonPeriod<$a, $b> as Period>::DENOMINATOR },
+                >;
+            }
+
+            impl CommonPeriodWith<$a> for $b {
+                type Output = <$a as CommonPeriodWith<$b>>::Output;
+            }
+        )*
+        impl_common_period_with!($($b),*);
+    }
+}
+
+named_periods!(impl_common_period_with);
+
+/// Sums the Numerators over the lcm of the Denominators, which are positive
+const fn ratio_add(r1: (i128, i128), r2: (i128, i128)) -> (i128, i128) {
+    let den = expect_period!(lcm(r1.1 as u128, r2.1 as u128));
+    let num = match (
+        r1.0.checked_mul((den / r1.1 as u128) as i128),
+        r2.0.checked_mul((den / r2.1 as u128) as i128),
+    ) {
+        (Some(a), Some(b)) => a.checked_add(b),
+        _ => None,
+    };
+    normalize_signed(num, den)
+}
+
+const fn ratio_subtract(r1: (i128, i128), r2: (i128, i128)) -> (i128, i128) {
+    let den = expect_period!(lcm(r1.1 as u128, r2.1 as u128));
+    let num = match (
+        r1.0.checked_mul((den / r1.1 as u128) as i128),
+        r2.0.checked_mul((den / r2.1 as u128) as i128),
+    ) {
+        (Some(a), Some(b)) => a.checked_sub(b),
+        _ => None,
+    };
+    normalize_signed(num, den)
+}
+
+const fn normalize_signed(num: Option<i128>, den: u128) -> (i128, i128) {
+    let num = expect_period!(num);
+    normalize(num < 0, num.unsigned_abs(), den)
+}
+
+/// The lcm of the Numerators over the gcd of the Denominators, which are positive
+const fn ratio_lcm(r1: (i128, i128), r2: (i128, i128)) -> (i128, i128) {
+    normalize(
+        false,
+        expect_period!(lcm(r1.0.unsigned_abs(), r2.0.unsigned_abs())),
+        gcd(r1.1 as u128, r2.1 as u128),
+    )
+}
+
+/// The sum of the Periods A and B, equivalent to std::ratio_add.
+/// It is a compile-time error to use the Period if the sum overflows i128.
+pub struct RatioAdd<A, B>(PhantomData<A>, PhantomData<B>);
+
+impl<A: sealed::Sealed, B: sealed::Sealed> sealed::Sealed for RatioAdd<A, B> {}
+
+impl<A: Period, B: Period> Period for RatioAdd<A, B> {
+    const NUMERATOR: i128 = ratio_add(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .0;
+    const DENOMINATOR: i128 = ratio_add(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .1;
+}
+
+/// The difference of the Periods A and B, equivalent to std::ratio_subtract.
+/// It is a compile-time error to use the Period if the difference overflows i128.
+pub struct RatioSubtract<A, B>(PhantomData<A>, PhantomData<B>);
+
+impl<A: sealed::Sealed, B: sealed::Sealed> sealed::Sealed for RatioSubtract<A, B> {}
+
+impl<A: Period, B: Period> Period for RatioSubtract<A, B> {
+    const NUMERATOR: i128 = ratio_subtract(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .0;
+    const DENOMINATOR: i128 = ratio_subtract(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .1;
+}
+
+/// The greatest Period of which both A and B are integer multiples, which is the CommonPeriod of A and B
+pub type RatioGcd<A, B> = CommonPeriod<A, B>;
+
+/// The least Period which is an integer multiple of both A and B.
+/// The Numerator is the lcm of the Numerators of A and B, and the Denominator is the gcd of the Denominators of A and B.
+/// It is a compile-time error to use the Period if the lcm overflows i128.
+pub struct RatioLcm<A, B>(PhantomData<A>, PhantomData<B>);
+
+impl<A: sealed::Sealed, B: sealed::Sealed> sealed::Sealed for RatioLcm<A, B> {}
+
+impl<A: Period, B: Period> Period for RatioLcm<A, B> {
+    const NUMERATOR: i128 = ratio_lcm(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .0;
+    const DENOMINATOR: i128 = ratio_lcm(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+    .1;
+}
+
+/// Checks if the Periods A and B are equal, equivalent to std::ratio_equal
+pub const fn ratio_eq<A: Period, B: Period>() -> bool {
+    A::NUMERATOR == B::NUMERATOR && A::DENOMINATOR == B::DENOMINATOR
+}
+
+/// Checks if the Periods A and B are not equal, equivalent to std::ratio_not_equal
+pub const fn ratio_ne<A: Period, B: Period>() -> bool {
+    !ratio_eq::<A, B>()
+}
+
+/// Checks if the Period A is less than B, equivalent to std::ratio_less
+pub const fn ratio_lt<A: Period, B: Period>() -> bool {
+    less(
+        (A::NUMERATOR, A::DENOMINATOR),
+        (B::NUMERATOR, B::DENOMINATOR),
+    )
+}
+
+/// Checks if the Period A is less than or equal to B, equivalent to std::ratio_less_equal
+pub const fn ratio_le<A: Period, B: Period>() -> bool {
+    !ratio_lt::<B, A>()
+}
+
+/// Checks if the Period A is greater than B, equivalent to std::ratio_greater
+pub const fn ratio_gt<A: Period, B: Period>() -> bool {
+    ratio_lt::<B, A>()
+}
+
+/// Checks if the Period A is greater than or equal to B, equivalent to std::ratio_greater_equal
+pub const fn ratio_ge<A: Period, B: Period>() -> bool {
+    !ratio_lt::<A, B>()
+}
+
+/// Checks if B is an integer multiple of the Period A, such that a Duration with Period B can be converted to a Duration with Period A without loss
+pub const fn divides_evenly<A: Period, B: Period>() -> bool {
+    // As both Periods are in lowest terms, B/A = (B::NUMERATOR * A::DENOMINATOR) / (A::NUMERATOR * B::DENOMINATOR)
+    // is an integer exactly when A::NUMERATOR divides B::NUMERATOR and B::DENOMINATOR divides A::DENOMINATOR
+    A::NUMERATOR != 0
+        && B::NUMERATOR.unsigned_abs() % A::NUMERATOR.unsigned_abs() == 0
+        && A::DENOMINATOR % B::DENOMINATOR == 0
+}
+
+/// Marker trait for Periods which evenly divide the Period B, that is, where B is an integer multiple of Self,
+/// such that a Duration with Period B can be converted to a Duration with Self without loss.
+///
+/// As the relation cannot be expressed for arbitrary Ratios, it is only implemented for each Period with itself, and between the named Periods,
+/// which are the SI Periods and the Periods of the Duration aliases, such as Mili: DividesEvenly<Unit> and Ratio<60, 1>: DividesEvenly<Ratio<3600, 1>>.
+/// It is not implemented between other Ratios, even where one evenly divides the other, such as Ratio<1, 6>: DividesEvenly<Ratio<1, 3>>,
+/// or between another Ratio and a named Period, such as Unit: DividesEvenly<Ratio<2, 1>>, so the lossless From conversions bounded on it are not provided for them.
+/// Generic code which needs the relation for arbitrary Periods can use divides_evenly instead, and convert by DurationCast.
+pub trait DividesEvenly<B: Period>: Period {}
+
+impl<P: Period> DividesEvenly<P> for P {}
+
+// Each entry of the table is checked against divides_evenly at compile time
+macro_rules! impl_divides_evenly{
+    ($($a:ty => [$($b:ty),*]);* $(;)?) => {
+        $($(
+            const _: () = assert!(divides_evenly::<$a, $b>());
+            impl DividesEvenly<$b> for $a {}
+        )*)*
+    }
+}
+
+impl_divides_evenly! {
+    Zetta => [Yotta];
+    Exa => [Yotta, Zetta];
+    Peta => [Yotta, Zetta, Exa];
+    Tera => [Yotta, Zetta, Exa, Peta];
+    Giga => [Yotta, Zetta, Exa, Peta, Tera];
+    Mega => [Yotta, Zetta, Exa, Peta, Tera, Giga];
+    Kilo => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega];
+    Hecto => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>];
+    Deca => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>];
+    Ratio<2629746, 1> => [Ratio<31556952, 1>];
+    Ratio<86400, 1> => [Ratio<604800, 1>];
+    Ratio<3600, 1> => [Ratio<604800, 1>, Ratio<86400, 1>];
+    Ratio<60, 1> => [Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>];
+    Unit => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>];
+    Deci => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit];
+    Centi => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci];
+    Mili => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi];
+    Micro => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili];
+    Nano => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili, Micro];
+    Pico => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili, Micro, Nano];
+    Femto => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili, Micro, Nano, Pico];
+    Atto => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili, Micro, Nano, Pico, Femto];
+    Zepto => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili, Micro, Nano, Pico, Femto, Atto];
+    Yocto => [Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca, Ratio<31556952, 1>, Ratio<2629746, 1>, Ratio<604800, 1>, Ratio<86400, 1>, Ratio<3600, 1>, Ratio<60, 1>, Unit, Deci, Centi, Mili, Micro, Nano, Pico, Femto, Atto, Zepto];
+}
+
+/// Computes the number of decimal digits needed to represent fractions of a second in units of 1/den seconds exactly,
+/// or 6 if no number of digits up to 24, the width of Yocto, suffices.
+const fn fractional_width(den: i128) -> u32 {
+    let mut width = 0;
+    let mut scale = 1i128;
+    loop {
+        if scale % den == 0 {
+            return width;
+        } else if width == 24 {
+            return 6;
+        }
+        scale *= 10;
+        width += 1;
+    }
+}
+
+/// The decimal Period which can exactly represent the fractional seconds of P, equivalent to the precision of std::chrono::hh_mm_ss.
+/// The Numerator is 1, and the Denominator is 10^w, where w is the least number of digits, up to 24, for which 1/10^w is a divisor of P,
+/// or 6 if there is no such number of digits.
+pub struct SubsecondPrecision<P>(PhantomData<P>);
+
+impl<P: sealed::Sealed> sealed::Sealed for SubsecondPrecision<P> {}
+
+impl<P: Period> SubsecondPrecision<P> {
+    /// The number of fractional digits of the Period
+    pub const WIDTH: u32 = fractional_width(P::DENOMINATOR);
+}
+
+impl<P: Period> Period for SubsecondPrecision<P> {
+    const NUMERATOR: i128 = 1;
+    const DENOMINATOR: i128 = 10i128.pow(Self::WIDTH);
+}
+
+/// A ratio whose value is only known at runtime, such as the tick period of a hardware counter, or the timebase of a media container.
+///
+/// Like the Period of a Ratio, the value is kept in lowest terms, with a positive denominator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynRatio {
+    num: i128,
+    den: i128,
+}
+
+impl DynRatio {
+    /// Creates the ratio num/den in lowest terms, or returns None if den is zero, or if the result does not fit in i128
+    pub const fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        match checked_normalize(
+            (num < 0) != (den < 0),
+            num.unsigned_abs(),
+            den.unsigned_abs(),
+        ) {
+            Some((num, den)) => Some(Self { num, den }),
+            None => None,
+        }
+    }
+
+    /// The value of the Period P
+    pub const fn of<P: Period>() -> Self {
+        Self {
+            num: P::NUMERATOR,
+            den: P::DENOMINATOR,
+        }
+    }
+
+    pub const fn numerator(self) -> i128 {
+        self.num
+    }
+
+    pub const fn denominator(self) -> i128 {
+        self.den
+    }
+
+    /// Multiplies the ratios, or returns None if the product does not fit in i128
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match checked_multiply((self.num, self.den), (rhs.num, rhs.den)) {
+            Some((num, den)) => Some(Self { num, den }),
+            None => None,
+        }
+    }
+
+    /// Divides the ratios, or returns None if rhs is zero, or if the quotient does not fit in i128
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.num == 0 {
+            return None;
+        }
+        match checked_multiply((self.num, self.den), (rhs.den, rhs.num)) {
+            Some((num, den)) => Some(Self { num, den }),
+            None => None,
+        }
+    }
+}
+
+impl<const N: i128, const D: i128> From<Ratio<N, D>> for DynRatio {
+    fn from(_: Ratio<N, D>) -> DynRatio {
+        DynRatio::of::<Ratio<N, D>>()
+    }
+}
+
+impl From<DynRatio> for f64 {
+    fn from(r: DynRatio) -> f64 {
+        (r.num as f64) / (r.den as f64)
+    }
+}
+
+impl PartialOrd for DynRatio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DynRatio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if less((self.num, self.den), (other.num, other.den)) {
+            Ordering::Less
+        } else if self == other {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+/// Formats as num/den, such as 1/19200000
+impl Display for DynRatio {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
 }
 
+pub type Yotta = Ratio<1_000_000_000_000_000_000_000_000, 1>;
+pub type Zetta = Ratio<1_000_000_000_000_000_000_000, 1>;
+pub type Exa = Ratio<1_000_000_000_000_000_000, 1>;
+pub type Peta = Ratio<1_000_000_000_000_000, 1>;
 pub type Tera = Ratio<1_000_000_000_000, 1>;
 pub type Giga = Ratio<1_000_000_000, 1>;
 pub type Mega = Ratio<1_000_000, 1>;
 pub type Kilo = Ratio<1_000, 1>;
+pub type Hecto = Ratio<100, 1>;
+pub type Deca = Ratio<10, 1>;
 pub type Unit = Ratio<1, 1>;
+pub type Deci = Ratio<1, 10>;
+pub type Centi = Ratio<1, 100>;
 pub type Mili = Ratio<1, 1_000>;
 pub type Micro = Ratio<1, 1_000_000>;
 pub type Nano = Ratio<1, 1_000_000_000>;
 pub type Pico = Ratio<1, 1_000_000_000_000>;
+pub type Femto = Ratio<1, 1_000_000_000_000_000>;
+pub type Atto = Ratio<1, 1_000_000_000_000_000_000>;
+pub type Zepto = Ratio<1, 1_000_000_000_000_000_000_000>;
+pub type Yocto = Ratio<1, 1_000_000_000_000_000_000_000_000>;
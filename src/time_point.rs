@@ -1,9 +1,13 @@
 use bytemuck::{Pod, Zeroable};
 
-use crate::{duration::IsDuration, prelude::*};
+use crate::{
+    duration::{DurationInto, DurationTryInto, IsDuration},
+    prelude::*,
+};
 
 use std::{
     cmp::*,
+    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
     marker::PhantomData,
     ops::*,
@@ -49,6 +53,7 @@ impl<_Clock: Clock, _Duration: IsDuration> TimePoint<_Clock, _Duration> {
             _phantom: PhantomData,
         }
     }
+    #[allow(clippy::type_complexity)]
     pub fn try_into<R2>(
         self,
     ) -> Result<
@@ -74,6 +79,88 @@ pub fn time_point_cast<D1: DurationCast<D2>, D2: IsDuration, _Clock: Clock>(
     })
 }
 
+/// Converts a TimePoint to a TimePoint with a different Duration, rounding towards negative infinity
+pub fn time_point_floor<D1: DurationCast<D2>, D2: IsDuration, _Clock: Clock>(
+    _tp: TimePoint<_Clock, D1>,
+) -> Result<TimePoint<_Clock, D2>, <D1 as DurationCast<D2>>::Error> {
+    _tp.value.duration_floor().map(|value| TimePoint {
+        value,
+        _phantom: PhantomData,
+    })
+}
+
+/// Converts a TimePoint to a TimePoint with a different Duration, rounding towards positive infinity
+pub fn time_point_ceil<D1: DurationCast<D2>, D2: IsDuration, _Clock: Clock>(
+    _tp: TimePoint<_Clock, D1>,
+) -> Result<TimePoint<_Clock, D2>, <D1 as DurationCast<D2>>::Error> {
+    _tp.value.duration_ceil().map(|value| TimePoint {
+        value,
+        _phantom: PhantomData,
+    })
+}
+
+/// Converts a TimePoint to a TimePoint with a different Duration, rounding to the nearest TimePoint, and to even in the case of a tie
+pub fn time_point_round<D1: DurationCast<D2>, D2: IsDuration, _Clock: Clock>(
+    _tp: TimePoint<_Clock, D1>,
+) -> Result<TimePoint<_Clock, D2>, <D1 as DurationCast<D2>>::Error> {
+    _tp.value.duration_round().map(|value| TimePoint {
+        value,
+        _phantom: PhantomData,
+    })
+}
+
+macro_rules! impl_integer_offsets{
+    ($($ty:ty),*) => {
+        $(
+            impl<_Clock, _P> TimePoint<_Clock, Duration<$ty, _P>> {
+                /// Offsets the TimePoint forwards by d, returning None if the result overflows
+                pub fn checked_add(self, d: Duration<$ty, _P>) -> Option<Self> {
+                    self.value.checked_add(d).map(TimePoint::new)
+                }
+
+                /// Offsets the TimePoint backwards by d, returning None if the result overflows
+                pub fn checked_sub(self, d: Duration<$ty, _P>) -> Option<Self> {
+                    self.value.checked_sub(d).map(TimePoint::new)
+                }
+
+                /// Offsets the TimePoint forwards by d, saturating at TimePoint::MIN and TimePoint::MAX
+                pub fn saturating_add(self, d: Duration<$ty, _P>) -> Self {
+                    TimePoint::new(self.value.saturating_add(d))
+                }
+
+                /// Offsets the TimePoint backwards by d, saturating at TimePoint::MIN and TimePoint::MAX
+                pub fn saturating_sub(self, d: Duration<$ty, _P>) -> Self {
+                    TimePoint::new(self.value.saturating_sub(d))
+                }
+
+                /// Offsets the TimePoint forwards by d, wrapping around at the bounds of the Repr
+                pub fn wrapping_add(self, d: Duration<$ty, _P>) -> Self {
+                    TimePoint::new(self.value.wrapping_add(d))
+                }
+
+                /// Offsets the TimePoint backwards by d, wrapping around at the bounds of the Repr
+                pub fn wrapping_sub(self, d: Duration<$ty, _P>) -> Self {
+                    TimePoint::new(self.value.wrapping_sub(d))
+                }
+
+                /// Offsets the TimePoint forwards by d, returning the wrapped result, and whether the result overflowed
+                pub fn overflowing_add(self, d: Duration<$ty, _P>) -> (Self, bool) {
+                    let (value, overflow) = self.value.overflowing_add(d);
+                    (TimePoint::new(value), overflow)
+                }
+
+                /// Offsets the TimePoint backwards by d, returning the wrapped result, and whether the result overflowed
+                pub fn overflowing_sub(self, d: Duration<$ty, _P>) -> (Self, bool) {
+                    let (value, overflow) = self.value.overflowing_sub(d);
+                    (TimePoint::new(value), overflow)
+                }
+            }
+        )*
+    }
+}
+
+impl_integer_offsets!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 impl<D1: Add<D2>, D2, _Clock: Clock> Add<D2> for TimePoint<_Clock, D1> {
     type Output = TimePoint<_Clock, <D1 as Add<D2>>::Output>;
     fn add(self, rhs: D2) -> Self::Output {
@@ -149,3 +236,16 @@ impl<D1: Clone, _Clock> Clone for TimePoint<_Clock, D1> {
 }
 
 impl<D1: Copy, _Clock> Copy for TimePoint<_Clock, D1> {}
+
+impl<D1: Debug, _Clock> Debug for TimePoint<_Clock, D1> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_tuple("TimePoint").field(&self.value).finish()
+    }
+}
+
+/// Formats the TimePoint as its Duration since the epoch of the Clock, as by the Display impl of the Duration
+impl<D1: Display, _Clock> Display for TimePoint<_Clock, D1> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
@@ -0,0 +1,536 @@
+use cxx_chrono::calendar::*;
+use cxx_chrono::clock::SystemClock;
+use cxx_chrono::duration::{Days, Hours, Miliseconds, Months, Nanoseconds, Seconds, Weeks, Years};
+use cxx_chrono::prelude::*;
+use std::convert::TryFrom;
+
+fn ymd(y: i32, m: u8, d: u8) -> YearMonthDay {
+    YearMonthDay::new(Year::new(y), Month::new(m), Day::new(d))
+}
+
+fn days(n: i64) -> SysDays {
+    TimePoint::new(Duration::new(n))
+}
+
+fn days_of(date: YearMonthDay) -> i64 {
+    SysDays::try_from(date).unwrap().into_inner().into_inner()
+}
+
+#[test]
+fn epoch_and_known_days() {
+    assert_eq!(days_of(ymd(1970, 1, 1)), 0);
+    assert_eq!(days_of(ymd(2000, 3, 1)), 11017);
+    assert_eq!(YearMonthDay::try_from(days(-1)), Ok(ymd(1969, 12, 31)));
+    assert_eq!(YearMonthDay::try_from(days(19782)), Ok(ymd(2024, 2, 29)));
+}
+
+#[test]
+fn round_trip_at_year_bounds() {
+    for y in [
+        Year::MIN.into_inner(),
+        Year::MIN.into_inner() + 1,
+        -1,
+        0,
+        1,
+        Year::MAX.into_inner(),
+    ] {
+        for (m, d) in [(1, 1), (2, 28), (3, 1), (12, 31)] {
+            let t = SysDays::try_from(ymd(y, m, d)).unwrap();
+            assert_eq!(YearMonthDay::try_from(t), Ok(ymd(y, m, d)));
+        }
+    }
+    let first = days_of(ymd(Year::MIN.into_inner(), 1, 1));
+    let last = days_of(ymd(Year::MAX.into_inner(), 12, 31));
+    assert_eq!(
+        YearMonthDay::try_from(days(first - 1)),
+        Err(CalendarError::OutOfRange)
+    );
+    assert_eq!(
+        YearMonthDay::try_from(days(last + 1)),
+        Err(CalendarError::OutOfRange)
+    );
+    assert_eq!(
+        YearMonthDay::try_from(days(i64::MIN)),
+        Err(CalendarError::OutOfRange)
+    );
+    assert_eq!(
+        YearMonthDay::try_from(days(i64::MAX)),
+        Err(CalendarError::OutOfRange)
+    );
+}
+
+#[test]
+fn round_trip_consecutive_days() {
+    for n in -200_000i64..200_000 {
+        let date = YearMonthDay::try_from(days(n)).unwrap();
+        assert!(date.is_ok());
+        assert_eq!(days_of(date), n);
+    }
+}
+
+#[test]
+fn leap_years() {
+    assert!(Year::new(2024).is_leap());
+    assert!(!Year::new(2023).is_leap());
+    assert!(Year::new(2000).is_leap());
+    assert!(!Year::new(1900).is_leap());
+    assert!(Year::new(0).is_leap());
+    assert!(Year::new(-4).is_leap());
+    assert!(!Year::new(-100).is_leap());
+    assert!(ymd(2024, 2, 29).is_ok());
+    assert!(!ymd(2023, 2, 29).is_ok());
+    assert!(!ymd(1900, 2, 29).is_ok());
+    assert_eq!(
+        SysDays::try_from(ymd(2023, 2, 29)),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(days_of(ymd(2000, 3, 1)) - days_of(ymd(2000, 2, 28)), 2);
+    assert_eq!(days_of(ymd(1900, 3, 1)) - days_of(ymd(1900, 2, 28)), 1);
+}
+
+#[test]
+fn weekdays() {
+    assert_eq!(Weekday::from(days(0)), Weekday::THURSDAY);
+    assert_eq!(Weekday::from(days(-1)), Weekday::WEDNESDAY);
+    assert_eq!(
+        Weekday::from(SysDays::try_from(ymd(2000, 1, 1)).unwrap()),
+        Weekday::SATURDAY
+    );
+    assert!(Weekday::from(days(i64::MIN)).is_ok());
+    assert!(Weekday::from(days(i64::MAX)).is_ok());
+    assert_eq!(Weekday::new(7), Weekday::SUNDAY);
+    assert_eq!(Weekday::SUNDAY.c_encoding(), 0);
+    assert_eq!(Weekday::SUNDAY.iso_encoding(), 7);
+}
+
+fn iso(y: i32, m: u8, d: u8) -> IsoWeekDate {
+    IsoWeekDate::try_from(SysDays::try_from(ymd(y, m, d)).unwrap()).unwrap()
+}
+
+#[test]
+fn iso_week_dates() {
+    assert_eq!(iso(2010, 1, 3).to_string(), "2009-W53-7");
+    assert_eq!(iso(2008, 12, 29).to_string(), "2009-W01-1");
+    assert_eq!(iso(2005, 1, 1).to_string(), "2004-W53-6");
+    assert_eq!(iso(2007, 12, 31).to_string(), "2008-W01-1");
+    assert_eq!(iso(2008, 1, 1).to_string(), "2008-W01-2");
+    assert_eq!(iso(2026, 10, 14).to_string(), "2026-W42-3");
+    assert_eq!(IsoWeekDate::weeks_in_year(Year::new(2020)), 53);
+    assert_eq!(IsoWeekDate::weeks_in_year(Year::new(2025)), 52);
+    assert!(!IsoWeekDate::new(Year::new(2025), 53, Weekday::MONDAY).is_ok());
+    assert_eq!(
+        SysDays::try_from(IsoWeekDate::new(Year::new(2025), 53, Weekday::MONDAY)),
+        Err(CalendarError::InvalidDate)
+    );
+}
+
+#[test]
+fn iso_week_date_round_trips() {
+    for n in -200_000i64..200_000 {
+        let date = IsoWeekDate::try_from(days(n)).unwrap();
+        assert!(date.is_ok());
+        assert_eq!(SysDays::try_from(date), Ok(days(n)));
+    }
+    for y in [Year::MIN, Year::MAX] {
+        for week in 1..=IsoWeekDate::weeks_in_year(y) {
+            for weekday in 1..=7 {
+                let date = IsoWeekDate::new(y, week, Weekday::new(weekday));
+                let t = SysDays::try_from(date).unwrap();
+                assert_eq!(IsoWeekDate::try_from(t), Ok(date));
+            }
+        }
+    }
+    let last = IsoWeekDate::new(
+        Year::MAX,
+        IsoWeekDate::weeks_in_year(Year::MAX),
+        Weekday::SUNDAY,
+    );
+    let last = SysDays::try_from(last).unwrap().into_inner().into_inner();
+    assert_eq!(
+        IsoWeekDate::try_from(days(last + 1)),
+        Err(CalendarError::OutOfRange)
+    );
+    let first = IsoWeekDate::new(Year::MIN, 1, Weekday::MONDAY);
+    let first = SysDays::try_from(first).unwrap().into_inner().into_inner();
+    assert_eq!(
+        IsoWeekDate::try_from(days(first - 1)),
+        Err(CalendarError::OutOfRange)
+    );
+}
+
+#[test]
+fn ordinal_dates() {
+    let date = OrdinalDate::new(Year::new(2026), 291);
+    assert!(date.is_ok());
+    assert_eq!(date.to_string(), "2026-291");
+    assert_eq!(
+        SysDays::try_from(date),
+        SysDays::try_from(ymd(2026, 10, 18))
+    );
+    assert_eq!(
+        OrdinalDate::try_from(days(0)),
+        Ok(OrdinalDate::new(Year::new(1970), 1))
+    );
+    assert_eq!(OrdinalDate::new(Year::new(1970), 1).to_string(), "1970-001");
+    assert_eq!(
+        OrdinalDate::try_from(SysDays::try_from(ymd(2024, 12, 31)).unwrap()),
+        Ok(OrdinalDate::new(Year::new(2024), 366))
+    );
+    assert_eq!(OrdinalDate::days_in_year(Year::new(2100)), 365);
+
+    let invalid = OrdinalDate::new(Year::new(2025), 366);
+    assert!(!invalid.is_ok());
+    assert!(!OrdinalDate::new(Year::new(2025), 0).is_ok());
+    assert_eq!(invalid.to_string(), "2025-366 is not a valid date");
+    assert_eq!(SysDays::try_from(invalid), Err(CalendarError::InvalidDate));
+
+    for n in [
+        days_of(ymd(Year::MIN.into_inner(), 1, 1)),
+        -1,
+        59,
+        days_of(ymd(Year::MAX.into_inner(), 12, 31)),
+    ] {
+        let date = OrdinalDate::try_from(days(n)).unwrap();
+        assert_eq!(SysDays::try_from(date), Ok(days(n)));
+    }
+}
+
+#[test]
+fn julian_days() {
+    let epoch = TimePoint::<SystemClock, Seconds>::new(Seconds::new(0));
+    assert_eq!(julian_day(epoch).unwrap().into_inner(), 2440587.5);
+    assert_eq!(modified_julian_date(epoch).unwrap().into_inner(), 40587.0);
+    assert_eq!(
+        from_julian_day::<i64, Unit>(FractionalDays::new(2440587.5)),
+        Ok(epoch)
+    );
+    assert_eq!(
+        from_modified_julian_date::<i64, Unit>(FractionalDays::new(40587.0)),
+        Ok(epoch)
+    );
+
+    // J2000, 2000-01-01T12:00:00
+    let noon = SysDays::try_from(ymd(2000, 1, 1)).unwrap().into_inner() + Hours::new(12);
+    let j2000 = TimePoint::<SystemClock, Seconds>::new(noon.duration_cast().unwrap());
+    assert_eq!(julian_day(j2000).unwrap().into_inner(), 2451545.0);
+    assert_eq!(modified_julian_date(j2000).unwrap().into_inner(), 51544.5);
+    assert_eq!(
+        from_julian_day::<i64, Unit>(FractionalDays::new(2451545.0)),
+        Ok(j2000)
+    );
+    assert_eq!(
+        from_modified_julian_date::<i64, Mili>(FractionalDays::new(51544.5))
+            .map(|tp| tp.into_inner()),
+        Ok(Miliseconds::new(946_728_000_000))
+    );
+    assert_eq!(
+        (j2000.into_inner() + JULIAN_DAY_OFFSET) / Days::new(1),
+        2451545
+    );
+}
+
+#[test]
+fn julian_day_precision() {
+    let ns = Nanoseconds::new(1_760_000_000_123_456_789);
+    let tp = TimePoint::<SystemClock, Nanoseconds>::new(ns);
+
+    let mjd = modified_julian_date(tp).unwrap();
+    let back: TimePoint<SystemClock, Nanoseconds> = from_modified_julian_date(mjd).unwrap();
+    assert!((back.into_inner().into_inner() - ns.into_inner()).abs() < 1_000);
+
+    let jd = julian_day(tp).unwrap();
+    let back: TimePoint<SystemClock, Nanoseconds> = from_julian_day(jd).unwrap();
+    assert!((back.into_inner().into_inner() - ns.into_inner()).abs() < 50_000);
+
+    // The offsets compute the exact Julian Day and Modified Julian Date in the Period of the Duration
+    assert_eq!(
+        (tp.into_inner() + JULIAN_DAY_OFFSET).into_inner(),
+        210_866_760_000_000_000_000 + 1_760_000_000_123_456_789
+    );
+    assert_eq!(
+        (tp.into_inner() + MODIFIED_JULIAN_DATE_OFFSET).into_inner(),
+        3_506_716_800_000_000_000 + 1_760_000_000_123_456_789
+    );
+}
+
+#[test]
+fn last_days() {
+    let feb = YearMonthDayLast::new(Year::new(2024), Month::new(2));
+    assert!(feb.is_ok());
+    assert_eq!(feb.day(), Day::new(29));
+    assert_eq!(feb.to_string(), "2024/Feb/last");
+    assert_eq!(YearMonthDay::from(feb), ymd(2024, 2, 29));
+    assert_eq!(
+        YearMonthDayLast::new(Year::new(2023), Month::new(2)).day(),
+        Day::new(28)
+    );
+    assert_eq!(
+        SysDays::try_from(YearMonthDayLast::new(Year::new(2024), Month::new(12))),
+        SysDays::try_from(ymd(2024, 12, 31))
+    );
+    let last = SysDays::try_from(ymd(2024, 2, 29)).unwrap();
+    assert_eq!(YearMonthDayLast::try_from(last), Ok(feb));
+    assert_eq!(
+        YearMonthDayLast::try_from(SysDays::try_from(ymd(2024, 2, 28)).unwrap()),
+        Err(CalendarError::InvalidDate)
+    );
+
+    let invalid = YearMonthDayLast::new(Year::new(2024), Month::new(13));
+    assert!(!invalid.is_ok());
+    assert_eq!(SysDays::try_from(invalid), Err(CalendarError::InvalidDate));
+}
+
+#[test]
+fn indexed_weekdays() {
+    // 2024-01-01 is a Monday
+    let second_tuesday =
+        YearMonthWeekday::new(Year::new(2024), Month::new(1), Weekday::TUESDAY.indexed(2));
+    assert!(second_tuesday.is_ok());
+    assert_eq!(second_tuesday.to_string(), "2024/Jan/Tue[2]");
+    assert_eq!(YearMonthDay::try_from(second_tuesday), Ok(ymd(2024, 1, 9)));
+    let days = SysDays::try_from(second_tuesday).unwrap();
+    assert_eq!(days, SysDays::try_from(ymd(2024, 1, 9)).unwrap());
+    assert_eq!(YearMonthWeekday::try_from(days), Ok(second_tuesday));
+
+    let fifth_wednesday = |m| {
+        YearMonthWeekday::new(
+            Year::new(2024),
+            Month::new(m),
+            Weekday::WEDNESDAY.indexed(5),
+        )
+    };
+    assert_eq!(
+        YearMonthDay::try_from(fifth_wednesday(1)),
+        Ok(ymd(2024, 1, 31))
+    );
+    assert!(!fifth_wednesday(2).is_ok());
+    assert_eq!(
+        YearMonthDay::try_from(fifth_wednesday(2)),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(
+        SysDays::try_from(fifth_wednesday(2)),
+        Err(CalendarError::InvalidDate)
+    );
+    let fifth_thursday =
+        |y| YearMonthWeekday::new(Year::new(y), Month::new(2), Weekday::THURSDAY.indexed(5));
+    assert_eq!(
+        YearMonthDay::try_from(fifth_thursday(2024)),
+        Ok(ymd(2024, 2, 29))
+    );
+    assert!(!fifth_thursday(2023).is_ok());
+    assert!(
+        !YearMonthWeekday::new(Year::new(2024), Month::new(1), Weekday::MONDAY.indexed(0)).is_ok()
+    );
+}
+
+#[test]
+fn last_weekdays() {
+    let last_friday =
+        YearMonthWeekdayLast::new(Year::new(2024), Month::new(1), Weekday::FRIDAY.last());
+    assert!(last_friday.is_ok());
+    assert_eq!(last_friday.to_string(), "2024/Jan/Fri[last]");
+    assert_eq!(YearMonthDay::try_from(last_friday), Ok(ymd(2024, 1, 26)));
+    let days = SysDays::try_from(last_friday).unwrap();
+    assert_eq!(YearMonthWeekdayLast::try_from(days), Ok(last_friday));
+    assert_eq!(
+        YearMonthWeekdayLast::try_from(SysDays::try_from(ymd(2024, 1, 19)).unwrap()),
+        Err(CalendarError::InvalidDate)
+    );
+    // The last day of the month is the last occurrence of its Weekday
+    let last_thursday =
+        YearMonthWeekdayLast::new(Year::new(2024), Month::new(2), Weekday::THURSDAY.last());
+    assert_eq!(YearMonthDay::try_from(last_thursday), Ok(ymd(2024, 2, 29)));
+
+    let invalid = YearMonthWeekdayLast::new(Year::new(2024), Month::new(1), Weekday::new(8).last());
+    assert!(!invalid.is_ok());
+    assert_eq!(SysDays::try_from(invalid), Err(CalendarError::InvalidDate));
+}
+
+#[test]
+fn add_months_day_overflow() {
+    let jan31 = ymd(2024, 1, 31);
+    assert_eq!(
+        jan31.add_months(Months::new(1), DayOverflow::Clamp),
+        Ok(ymd(2024, 2, 29))
+    );
+    assert_eq!(
+        jan31.add_months(Months::new(1), DayOverflow::Overflow),
+        Ok(ymd(2024, 3, 2))
+    );
+    assert_eq!(
+        jan31.add_months(Months::new(1), DayOverflow::Error),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(
+        jan31.add_months(Months::new(2), DayOverflow::Error),
+        Ok(ymd(2024, 3, 31))
+    );
+    assert_eq!(
+        jan31.add_months(Months::new(-2), DayOverflow::Clamp),
+        Ok(ymd(2023, 11, 30))
+    );
+    assert_eq!(
+        jan31.add_months(Months::new(13), DayOverflow::Overflow),
+        Ok(ymd(2025, 3, 3))
+    );
+    assert_eq!(
+        ymd(2024, 2, 30).add_months(Months::new(1), DayOverflow::Clamp),
+        Err(CalendarError::InvalidDate)
+    );
+}
+
+#[test]
+fn add_years_leap_day() {
+    let leap = ymd(2024, 2, 29);
+    assert_eq!(
+        leap.add_years(Years::new(1), DayOverflow::Clamp),
+        Ok(ymd(2025, 2, 28))
+    );
+    assert_eq!(
+        leap.add_years(Years::new(1), DayOverflow::Overflow),
+        Ok(ymd(2025, 3, 1))
+    );
+    assert_eq!(
+        leap.add_years(Years::new(1), DayOverflow::Error),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(
+        leap.add_years(Years::new(4), DayOverflow::Error),
+        Ok(ymd(2028, 2, 29))
+    );
+    assert_eq!(
+        leap.add_years(Years::new(-100), DayOverflow::Clamp),
+        Ok(ymd(1924, 2, 29))
+    );
+    assert_eq!(
+        ymd(Year::MAX.into_inner(), 1, 1).add_years(Years::new(1), DayOverflow::Clamp),
+        Err(CalendarError::OutOfRange)
+    );
+}
+
+#[test]
+fn year_month_arithmetic() {
+    let ym = YearMonth::new(Year::new(2024), Month::JANUARY);
+    assert_eq!(
+        ym.add_months(Months::new(-1)),
+        Ok(YearMonth::new(Year::new(2023), Month::DECEMBER))
+    );
+    assert_eq!(
+        ym.add_months(Months::new(25)),
+        Ok(YearMonth::new(Year::new(2026), Month::FEBRUARY))
+    );
+    assert_eq!(
+        ym.add_years(Years::new(-2024)),
+        Ok(YearMonth::new(Year::new(0), Month::JANUARY))
+    );
+    assert_eq!(
+        YearMonth::new(Year::new(2024), Month::new(13)).add_months(Months::new(1)),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(
+        YearMonth::new(Year::MIN, Month::JANUARY).add_months(Months::new(-1)),
+        Err(CalendarError::OutOfRange)
+    );
+}
+
+#[test]
+fn months_and_years_between() {
+    assert_eq!(
+        months_between(ymd(2024, 1, 31), ymd(2024, 2, 29)),
+        Ok(Months::new(1))
+    );
+    assert_eq!(
+        months_between(ymd(2024, 1, 31), ymd(2024, 2, 28)),
+        Ok(Months::new(0))
+    );
+    assert_eq!(
+        months_between(ymd(2024, 1, 15), ymd(2024, 1, 15)),
+        Ok(Months::new(0))
+    );
+    assert_eq!(
+        months_between(ymd(2024, 3, 31), ymd(2024, 1, 31)),
+        Ok(Months::new(-2))
+    );
+    assert_eq!(
+        months_between(ymd(2024, 3, 15), ymd(2024, 1, 20)),
+        Ok(Months::new(-1))
+    );
+    assert_eq!(
+        months_between(ymd(2024, 3, 15), ymd(2024, 3, 1)),
+        Ok(Months::new(0))
+    );
+
+    assert_eq!(
+        years_between(ymd(2020, 2, 29), ymd(2024, 2, 28)),
+        Ok(Years::new(3))
+    );
+    assert_eq!(
+        years_between(ymd(2020, 2, 29), ymd(2024, 2, 29)),
+        Ok(Years::new(4))
+    );
+    assert_eq!(
+        years_between(ymd(2024, 6, 1), ymd(2022, 6, 2)),
+        Ok(Years::new(-1))
+    );
+    assert_eq!(
+        months_between(ymd(2024, 2, 30), ymd(2024, 3, 1)),
+        Err(CalendarError::InvalidDate)
+    );
+}
+
+#[test]
+fn time_point_calendar_arithmetic() {
+    let time_of_day = (13 * 3600 + 45 * 60 + 30) * 1000 + 500;
+    let at = |date: YearMonthDay| -> TimePoint<SystemClock, Miliseconds> {
+        TimePoint::new(Duration::new(days_of(date) * 86_400_000 + time_of_day))
+    };
+    assert_eq!(
+        at(ymd(2024, 1, 31)).add_months(Months::new(1), DayOverflow::Clamp),
+        Ok(at(ymd(2024, 2, 29)))
+    );
+    assert_eq!(
+        at(ymd(2024, 1, 31)).add_months(Months::new(1), DayOverflow::Overflow),
+        Ok(at(ymd(2024, 3, 2)))
+    );
+    assert_eq!(
+        at(ymd(2024, 2, 29)).add_years(Years::new(1), DayOverflow::Clamp),
+        Ok(at(ymd(2025, 2, 28)))
+    );
+    assert_eq!(
+        at(ymd(2024, 2, 29)).add_years(Years::new(1), DayOverflow::Error),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(
+        CalendarArithmetic::year_month(&at(ymd(2024, 2, 29))),
+        Ok(YearMonth::new(Year::new(2024), Month::FEBRUARY))
+    );
+    assert_eq!(
+        months_between(at(ymd(2024, 1, 31)), at(ymd(2024, 2, 29))),
+        Ok(Months::new(1))
+    );
+
+    // 1969-12-31T23:00 plus two months is 1970-02-28T23:00
+    let before_epoch: TimePoint<SystemClock, Seconds> = TimePoint::new(Duration::new(-3600));
+    assert_eq!(
+        before_epoch.add_months(Months::new(2), DayOverflow::Clamp),
+        Ok(TimePoint::new(Duration::new(
+            days_of(ymd(1970, 3, 1)) * 86_400 - 3600
+        )))
+    );
+    assert_eq!(
+        CalendarArithmetic::year_month(&before_epoch),
+        Ok(YearMonth::new(Year::new(1969), Month::DECEMBER))
+    );
+
+    // A Period longer than a day cannot represent the result exactly
+    let weeks: TimePoint<SystemClock, Weeks> = TimePoint::new(Duration::new(1));
+    assert!(matches!(
+        weeks.add_months(Months::new(1), DayOverflow::Clamp),
+        Err(CalendarError::Cast(_))
+    ));
+    assert_eq!(
+        days(0).add_months(Months::new(1), DayOverflow::Clamp),
+        Ok(days(31))
+    );
+}
@@ -0,0 +1,147 @@
+use cxx_chrono::calendar::*;
+use cxx_chrono::calendar_duration::*;
+use cxx_chrono::clock::SystemClock;
+use cxx_chrono::duration::*;
+use cxx_chrono::prelude::*;
+use std::convert::TryFrom;
+
+fn ymd(y: i32, m: u8, d: u8) -> YearMonthDay {
+    YearMonthDay::new(Year::new(y), Month::new(m), Day::new(d))
+}
+
+fn at(date: YearMonthDay, seconds: i64) -> TimePoint<SystemClock, Seconds> {
+    let days = SysDays::try_from(date).unwrap().into_inner().into_inner();
+    TimePoint::new(Duration::new(days * 86_400 + seconds))
+}
+
+fn calendar(years: i64, months: i64, days: i64, time: i128) -> CalendarDuration {
+    CalendarDuration::new(
+        Duration::new(years),
+        Duration::new(months),
+        Duration::new(days),
+        Duration::new(time),
+    )
+}
+
+#[test]
+fn between_dates() {
+    assert_eq!(
+        CalendarDuration::between_dates(ymd(2021, 1, 28), ymd(2024, 3, 1)),
+        Ok(calendar(3, 1, 2, 0))
+    );
+    assert_eq!(
+        CalendarDuration::between_dates(ymd(2024, 3, 1), ymd(2021, 1, 28)),
+        Ok(calendar(-3, -1, -4, 0))
+    );
+    assert_eq!(
+        CalendarDuration::between_dates(ymd(2024, 1, 31), ymd(2024, 2, 29)),
+        Ok(calendar(0, 1, 0, 0))
+    );
+    assert_eq!(
+        CalendarDuration::between_dates(ymd(2024, 1, 31), ymd(2024, 2, 28)),
+        Ok(calendar(0, 0, 28, 0))
+    );
+    assert_eq!(
+        CalendarDuration::between_dates(ymd(2024, 5, 5), ymd(2024, 5, 5)),
+        Ok(CalendarDuration::ZERO)
+    );
+    assert_eq!(
+        CalendarDuration::between_dates(ymd(2024, 2, 30), ymd(2024, 3, 1)),
+        Err(CalendarError::InvalidDate)
+    );
+}
+
+#[test]
+fn between_time_points() {
+    let (from, to) = (
+        at(ymd(2024, 1, 31), 12 * 3600),
+        at(ymd(2024, 3, 1), 6 * 3600 + 1800),
+    );
+    let forward = CalendarDuration::between(from, to).unwrap();
+    assert_eq!(
+        forward,
+        calendar(0, 1, 0, 18 * 3_600_000_000_000 + 1_800_000_000_000)
+    );
+    let backward = CalendarDuration::between(to, from).unwrap();
+    assert_eq!(
+        backward,
+        calendar(0, -1, 0, -(18 * 3_600_000_000_000 + 1_800_000_000_000))
+    );
+
+    let (from, to) = (at(ymd(2020, 6, 15), 0), at(ymd(2024, 6, 20), 90_061));
+    assert_eq!(
+        CalendarDuration::between(from, to),
+        Ok(calendar(4, 0, 6, 3_661_000_000_000))
+    );
+
+    // A third of a second cannot be represented exactly in Nanoseconds
+    let thirds = |n| TimePoint::<SystemClock, Duration<i64, Ratio<1, 3>>>::new(Duration::new(n));
+    assert!(matches!(
+        CalendarDuration::between(thirds(1), thirds(2)),
+        Err(CalendarError::Cast(_))
+    ));
+}
+
+#[test]
+fn add_calendar_duration() {
+    let from = at(ymd(2024, 1, 31), 12 * 3600);
+    let d = calendar(0, 1, 1, 12 * 3_600_000_000_000);
+    assert_eq!(
+        from.add_calendar_duration(d, DayOverflow::Clamp),
+        Ok(at(ymd(2024, 3, 2), 0))
+    );
+    assert_eq!(
+        from.add_calendar_duration(d, DayOverflow::Overflow),
+        Ok(at(ymd(2024, 3, 4), 0))
+    );
+    assert_eq!(
+        from.add_calendar_duration(d, DayOverflow::Error),
+        Err(CalendarError::InvalidDate)
+    );
+    assert_eq!(
+        at(ymd(2024, 2, 29), 0).add_calendar_duration(calendar(1, 0, 0, 0), DayOverflow::Clamp),
+        Ok(at(ymd(2025, 2, 28), 0))
+    );
+    assert_eq!(
+        from.add_calendar_duration(calendar(-1, -1, -1, 0), DayOverflow::Clamp),
+        Ok(at(ymd(2022, 12, 30), 12 * 3600))
+    );
+
+    // Adding the result of between to from yields to
+    let to = at(ymd(2027, 2, 28), 3723);
+    let d = CalendarDuration::between(from, to).unwrap();
+    assert_eq!(from.add_calendar_duration(d, DayOverflow::Clamp), Ok(to));
+
+    let day = SysDays::new(Duration::new(0));
+    assert!(matches!(
+        day.add_calendar_duration(
+            calendar(0, 0, 0, 12 * 3_600_000_000_000),
+            DayOverflow::Clamp
+        ),
+        Err(CalendarError::Cast(_))
+    ));
+    assert_eq!(
+        day.add_calendar_duration(calendar(i64::MAX, 0, 0, 0), DayOverflow::Clamp),
+        Err(CalendarError::OutOfRange)
+    );
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(calendar(1, 2, 3, 0).to_string(), "P1Y2M3D");
+    assert_eq!(calendar(-1, -2, -3, 0).to_string(), "-P1Y2M3D");
+    assert_eq!(calendar(-1, -2, 0, 0).to_string(), "-P1Y2M");
+    assert_eq!(CalendarDuration::ZERO.to_string(), "P0D");
+    assert_eq!(calendar(0, 0, 0, 5_000_000_000).to_string(), "PT5S");
+    assert_eq!(calendar(0, 0, 0, -5_000_000_000).to_string(), "-PT5S");
+    assert_eq!(calendar(0, 0, 0, 1_500_000_000).to_string(), "PT1.5S");
+    assert_eq!(
+        calendar(0, 1, 0, 12 * 3_600_000_000_000).to_string(),
+        "P1MT12H"
+    );
+    assert_eq!(calendar(0, 1, -3, 0).to_string(), "P1M-3D");
+    assert_eq!(
+        calendar(0, 0, 2, 3_661_000_000_001).to_string(),
+        "P2DT1H1M1.000000001S"
+    );
+}
@@ -0,0 +1,112 @@
+use cxx_chrono::clock::*;
+use cxx_chrono::duration::*;
+use cxx_chrono::prelude::*;
+use std::convert::TryFrom;
+use std::time::{Duration as StdDuration, Instant, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn system_time_floor_conversion() {
+    let t = UNIX_EPOCH + StdDuration::new(1, 999_999);
+    assert_eq!(
+        TimePoint::<SystemClock, Miliseconds>::try_from(t),
+        Err(DurationCastError::PrecisionLoss)
+    );
+    assert_eq!(
+        system_time_floor::<i64, Mili>(t).map(|tp| tp.into_inner().into_inner()),
+        Ok(1000)
+    );
+    let before = UNIX_EPOCH - StdDuration::new(0, 1500);
+    assert_eq!(
+        system_time_floor::<i64, Mili>(before).map(|tp| tp.into_inner().into_inner()),
+        Ok(-1)
+    );
+    let now = SystemTime::now();
+    let floored: TimePoint<SystemClock, Miliseconds> = system_time_floor(now).unwrap();
+    assert!(SystemTime::try_from(floored).unwrap() <= now);
+}
+
+#[test]
+fn std_duration_conversion() {
+    assert_eq!(
+        Miliseconds::try_from(StdDuration::from_millis(1500)),
+        Ok(Miliseconds::new(1500))
+    );
+    assert_eq!(
+        Seconds::try_from(StdDuration::from_millis(1500)),
+        Err(DurationCastError::PrecisionLoss)
+    );
+    assert_eq!(
+        Nanoseconds::try_from(StdDuration::new(u64::MAX, 999_999_999)),
+        Ok(Nanoseconds::new(
+            u64::MAX as i128 * 1_000_000_000 + 999_999_999
+        ))
+    );
+    assert_eq!(
+        Duration::<i32, Unit>::try_from(StdDuration::from_secs(1 << 40)),
+        Err(DurationCastError::Overflow)
+    );
+
+    assert_eq!(
+        StdDuration::try_from(Miliseconds::new(1500)),
+        Ok(StdDuration::from_millis(1500))
+    );
+    assert_eq!(
+        StdDuration::try_from(Duration::<f64, Unit>::new(0.25)),
+        Ok(StdDuration::from_millis(250))
+    );
+    assert_eq!(
+        StdDuration::try_from(Miliseconds::new(-1)),
+        Err(DurationCastError::Underflow)
+    );
+    assert_eq!(
+        StdDuration::try_from(Duration::<i128, Pico>::new(1500)),
+        Err(DurationCastError::PrecisionLoss)
+    );
+    assert_eq!(
+        StdDuration::try_from(Duration::<i128, Unit>::new(i128::from(u64::MAX) + 1)),
+        Err(DurationCastError::Overflow)
+    );
+}
+
+#[test]
+fn system_time_conversion() {
+    let t = UNIX_EPOCH + StdDuration::from_millis(1500);
+    let tp = TimePoint::<SystemClock, Miliseconds>::try_from(t).unwrap();
+    assert_eq!(tp.into_inner(), Miliseconds::new(1500));
+    assert_eq!(SystemTime::try_from(tp), Ok(t));
+
+    let before = UNIX_EPOCH - StdDuration::from_secs(2);
+    let tp = TimePoint::<SystemClock, Seconds>::try_from(before).unwrap();
+    assert_eq!(tp.into_inner(), Seconds::new(-2));
+    assert_eq!(SystemTime::try_from(tp), Ok(before));
+
+    assert_eq!(
+        SystemTime::try_from(TimePoint::<SystemClock, Duration<i128, Pico>>::new(
+            Duration::new(1)
+        )),
+        Err(DurationCastError::PrecisionLoss)
+    );
+    assert_eq!(
+        SystemTime::try_from(TimePoint::<SystemClock, Nanoseconds>::new(
+            Nanoseconds::new(i128::MIN)
+        )),
+        Err(DurationCastError::Underflow)
+    );
+}
+
+#[test]
+fn instant_conversion() {
+    let now = SteadyClock::now();
+    let before = Instant::now();
+    let deadline = Instant::try_from(now + Seconds::new(10)).unwrap();
+    let after = Instant::now();
+    assert!(deadline >= before + StdDuration::from_secs(10) - StdDuration::from_millis(100));
+    assert!(deadline <= after + StdDuration::from_secs(10));
+    assert!(Instant::try_from(now).unwrap() <= Instant::now());
+    assert_eq!(
+        Instant::try_from(TimePoint::<SteadyClock, Duration<i128, Pico>>::new(
+            Duration::new(1)
+        )),
+        Err(DurationCastError::PrecisionLoss)
+    );
+}
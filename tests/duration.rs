@@ -0,0 +1,509 @@
+use cxx_chrono::clock::{SteadyClock, SystemClock};
+use cxx_chrono::duration::*;
+use cxx_chrono::prelude::*;
+
+#[test]
+fn cross_period_comparison() {
+    assert_eq!(Seconds::new(1), Nanoseconds::new(1_000_000_000));
+    assert_ne!(
+        Duration::<i16, Unit>::new(1),
+        Duration::<i16, Micro>::new(1000)
+    );
+    assert_eq!(
+        Duration::<i16, Unit>::new(0),
+        Duration::<i16, Micro>::new(0)
+    );
+    assert!(Duration::<i16, Unit>::new(1) > Duration::<i16, Micro>::new(30000));
+    assert!(Duration::<i16, Unit>::new(-1) < Duration::<i16, Micro>::new(-30000));
+    assert!(Duration::<u128, Unit>::new(u128::MAX) > Duration::<i8, Unit>::new(-1));
+    assert!(Duration::<u128, Unit>::new(u128::MAX) > Duration::<u8, Ratio<60, 1>>::new(255));
+    assert_eq!(Duration::<f64, Unit>::new(1.5), Miliseconds::new(1500));
+    assert_ne!(Duration::<f64, Unit>::new(f64::NAN), Miliseconds::new(1500));
+    assert_eq!(Hours::new(1), Minutes::new(60));
+    assert!(Weeks::new(1) < Days::new(8));
+    assert!(Years::new(-1) < Months::new(-11));
+    assert!(Duration::<i64, Ratio<1, 3>>::new(1) > Duration::<i64, Ratio<1, 7>>::new(2));
+}
+
+#[test]
+fn common_repr_arithmetic() {
+    assert_eq!(
+        (Seconds::new(1) + Nanoseconds::new(5)).into_inner(),
+        1_000_000_005i128
+    );
+    assert_eq!(
+        (Duration::<i16, Unit>::new(0) + Duration::<i16, Micro>::new(5)).into_inner(),
+        5
+    );
+    assert_eq!(
+        (Duration::<i8, Unit>::new(1) % Duration::<u8, Mili>::new(7)).into_inner(),
+        6i16
+    );
+    assert_eq!(
+        (Duration::<f64, Unit>::new(1.5) + Miliseconds::new(1)).into_inner(),
+        1501.0
+    );
+    assert_eq!(Seconds::new(3) / Miliseconds::new(7), 428);
+    let start = SteadyClock::now();
+    assert!(start + Miliseconds::new(10) > start);
+}
+
+#[test]
+fn common_period_arithmetic() {
+    let sum: Seconds = Seconds::new(1) + Seconds::new(2);
+    assert_eq!(sum.into_inner(), 3);
+    let sum: Miliseconds = Seconds::new(1) + Miliseconds::new(5);
+    assert_eq!(sum.into_inner(), 1005);
+    let difference: Miliseconds = Miliseconds::new(5) - Seconds::new(1);
+    assert_eq!(difference.into_inner(), -995);
+    let remainder: Minutes = Hours::new(1) % Minutes::new(7);
+    assert_eq!(remainder.into_inner(), 4);
+    let sum: Duration<i64, Ratio<2629746, 1>> = Years::new(1) + Months::new(1);
+    assert_eq!(sum.into_inner(), 13);
+    let sum: Nanoseconds = Duration::<i32, Micro>::new(-1) + Nanoseconds::new(1);
+    assert_eq!(sum.into_inner(), -999);
+
+    let start = SteadyClock::now();
+    let mut t: TimePoint<SteadyClock, Nanoseconds> = start + Nanoseconds::new(1);
+    t += Nanoseconds::new(1);
+    assert_eq!(t.into_inner() - start.into_inner(), Nanoseconds::new(2));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn common_period_overflow() {
+    let _ = Duration::<i8, Unit>::new(1) + Duration::<i8, Mili>::new(0);
+}
+
+#[test]
+fn lossless_from() {
+    assert_eq!(Miliseconds::from(Seconds::new(2)).into_inner(), 2000);
+    assert_eq!(
+        Duration::<i16, Mili>::from(Duration::<i8, Unit>::new(-3)).into_inner(),
+        -3000
+    );
+    assert_eq!(
+        Duration::<i64, Nano>::from(Duration::<u8, Ratio<3600, 1>>::new(2)).into_inner(),
+        7_200_000_000_000
+    );
+    assert_eq!(
+        Duration::<f64, Unit>::from(Miliseconds::new(1500)).into_inner(),
+        1.5
+    );
+    assert_eq!(
+        Duration::<i64, Pico>::from(Duration::<i64, Nano>::new(3)).into_inner(),
+        3000
+    );
+    assert_eq!(
+        Seconds::from(Duration::<i64, Kilo>::new(2)).into_inner(),
+        2000
+    );
+    assert_eq!(Minutes::from(Weeks::new(1)).into_inner(), 10080);
+    assert_eq!(
+        Duration::<f64, Ratio<60, 1>>::from(Duration::<f64, Unit>::new(90.0)).into_inner(),
+        1.5
+    );
+    assert_eq!(
+        Duration::<f32, Unit>::from(Duration::<f32, Hecto>::new(0.5)).into_inner(),
+        50.0
+    );
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(Nanoseconds::new(-5).to_string(), "-5ns");
+    assert_eq!(Microseconds::new(3).to_string(), "3µs");
+    assert_eq!(format!("{:#}", Microseconds::new(3)), "3us");
+    assert_eq!(format!("{:?}", Microseconds::new(3)), "3µs");
+    assert_eq!(format!("{:#?}", Microseconds::new(3)), "3us");
+    assert_eq!(Miliseconds::new(250).to_string(), "250ms");
+    assert_eq!(Seconds::new(1).to_string(), "1s");
+    assert_eq!(Minutes::new(2).to_string(), "2min");
+    assert_eq!(Hours::new(3).to_string(), "3h");
+    assert_eq!(Days::new(4).to_string(), "4d");
+    assert_eq!(Duration::<i64, Kilo>::new(5).to_string(), "5ks");
+    assert_eq!(Weeks::new(1).to_string(), "1[604800]s");
+    assert_eq!(Duration::<i64, Ratio<1, 3>>::new(7).to_string(), "7[1/3]s");
+    assert_eq!(Duration::<i64, Ratio<2, 6>>::new(7).to_string(), "7[1/3]s");
+    assert_eq!(format!("{:.2}", Duration::<f64, Mili>::new(1.5)), "1.50ms");
+    assert_eq!(
+        format!("{:>6.1}", Duration::<f32, Unit>::new(0.25)),
+        "   0.2s"
+    );
+    assert_eq!(
+        TimePoint::<SystemClock, Miliseconds>::new(Miliseconds::new(1500)).to_string(),
+        "1500ms"
+    );
+}
+
+#[test]
+fn duration_cast_rounding() {
+    fn casts(ms: i64) -> [i64; 4] {
+        let d = Miliseconds::new(ms);
+        [
+            DurationCast::<Seconds>::duration_cast(d)
+                .unwrap()
+                .into_inner(),
+            DurationCast::<Seconds>::duration_floor(d)
+                .unwrap()
+                .into_inner(),
+            DurationCast::<Seconds>::duration_ceil(d)
+                .unwrap()
+                .into_inner(),
+            DurationCast::<Seconds>::duration_round(d)
+                .unwrap()
+                .into_inner(),
+        ]
+    }
+    assert_eq!(casts(1500), [1, 1, 2, 2]);
+    assert_eq!(casts(2500), [2, 2, 3, 2]);
+    assert_eq!(casts(-1500), [-1, -2, -1, -2]);
+    assert_eq!(casts(-2500), [-2, -3, -2, -2]);
+    assert_eq!(casts(-2501), [-2, -3, -2, -3]);
+    assert_eq!(casts(-2000), [-2, -2, -2, -2]);
+    assert_eq!(
+        DurationCast::<Miliseconds>::duration_cast(Seconds::new(i64::MIN)),
+        Err(DurationCastError::Underflow)
+    );
+}
+
+#[test]
+fn duration_cast_wide_intermediate() {
+    let years = Nanoseconds::new(i128::MAX);
+    assert_eq!(
+        DurationCast::<Duration<i128, Ratio<31556952, 1>>>::duration_floor(years)
+            .unwrap()
+            .into_inner(),
+        5391559471918239497011
+    );
+    assert_eq!(
+        DurationCast::<Years>::duration_cast(years),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        DurationCast::<Years>::duration_cast(-years),
+        Err(DurationCastError::Underflow)
+    );
+
+    // The product of the value and 11 overflows u128, so the cast divides in 256 bits
+    let d = Duration::<i128, Ratio<1, 7>>::new(85070591730234615865843651857942052862);
+    let cast = |d: Duration<i128, Ratio<1, 7>>| {
+        [
+            DurationCast::<Duration<i128, Ratio<1, 11>>>::duration_cast(d),
+            DurationCast::<Duration<i128, Ratio<1, 11>>>::duration_floor(d),
+            DurationCast::<Duration<i128, Ratio<1, 11>>>::duration_ceil(d),
+            DurationCast::<Duration<i128, Ratio<1, 11>>>::duration_round(d),
+        ]
+        .map(|d| d.unwrap().into_inner())
+    };
+    assert_eq!(
+        cast(d),
+        [
+            133682358433225824932040024348194654497,
+            133682358433225824932040024348194654497,
+            133682358433225824932040024348194654498,
+            133682358433225824932040024348194654497,
+        ]
+    );
+    assert_eq!(
+        cast(-d),
+        [
+            -133682358433225824932040024348194654497,
+            -133682358433225824932040024348194654498,
+            -133682358433225824932040024348194654497,
+            -133682358433225824932040024348194654497,
+        ]
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Ratio<1, 11>>>::duration_cast(
+            Duration::<i128, Ratio<1, 7>>::new(i128::MAX)
+        ),
+        Err(DurationCastError::Overflow)
+    );
+}
+
+#[test]
+fn duration_cast_i128_min() {
+    assert_eq!(
+        DurationCast::<Duration<i128, Unit>>::duration_cast(Duration::<i128, Ratio<2, 1>>::new(
+            i128::MIN / 2
+        )),
+        Ok(Duration::new(i128::MIN))
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Mili>>::duration_cast(Duration::<i128, Unit>::new(i128::MIN)),
+        Err(DurationCastError::Underflow)
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Micro>>::duration_floor(Nanoseconds::new(i128::MIN))
+            .unwrap()
+            .into_inner(),
+        i128::MIN / 1000 - 1
+    );
+
+    // -(2^127 - 1/2), which rounds to i128::MIN towards negative infinity, and to even
+    let d = Duration::<i128, Ratio<3, 1>>::new(-113427455640312821154458202477256070485);
+    assert_eq!(
+        DurationCast::<Duration<i128, Ratio<2, 1>>>::duration_cast(d)
+            .unwrap()
+            .into_inner(),
+        i128::MIN + 1
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Ratio<2, 1>>>::duration_floor(d)
+            .unwrap()
+            .into_inner(),
+        i128::MIN
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Ratio<2, 1>>>::duration_round(d)
+            .unwrap()
+            .into_inner(),
+        i128::MIN
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Ratio<2, 1>>>::duration_floor(
+            d - Duration::<i128, Ratio<3, 1>>::new(1)
+        )
+        .map(|d| d.into_inner()),
+        Err(DurationCastError::Underflow)
+    );
+}
+
+#[test]
+fn checked_arithmetic() {
+    let (min, max) = (Seconds::new(i64::MIN), Seconds::new(i64::MAX));
+    assert_eq!(
+        Seconds::new(5).checked_add(Seconds::new(-7)),
+        Some(Seconds::new(-2))
+    );
+    assert_eq!(max.checked_add(Seconds::new(1)), None);
+    assert_eq!(min.checked_sub(Seconds::new(1)), None);
+    assert_eq!(Seconds::new(6).checked_mul(-7), Some(Seconds::new(-42)));
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(Seconds::new(7).checked_div(-2), Some(Seconds::new(-3)));
+    assert_eq!(Seconds::new(7).checked_div(0), None);
+    assert_eq!(min.checked_div(-1), None);
+    assert_eq!(
+        Duration::<u8, Unit>::new(3).checked_sub(Duration::new(5)),
+        None
+    );
+}
+
+#[test]
+fn saturating_arithmetic() {
+    let (min, max) = (Seconds::new(i64::MIN), Seconds::new(i64::MAX));
+    assert_eq!(
+        Seconds::new(5).saturating_sub(Seconds::new(7)),
+        Seconds::new(-2)
+    );
+    assert_eq!(max.saturating_add(Seconds::new(1)), Seconds::MAX);
+    assert_eq!(min.saturating_sub(Seconds::new(1)), Seconds::MIN);
+    assert_eq!(min.saturating_add(min), Seconds::MIN);
+    assert_eq!(max.saturating_mul(2), Seconds::MAX);
+    assert_eq!(max.saturating_mul(-2), Seconds::MIN);
+    assert_eq!(min.saturating_div(-1), Seconds::MAX);
+    assert_eq!(Seconds::new(-7).saturating_div(2), Seconds::new(-3));
+    assert_eq!(
+        Duration::<u8, Unit>::new(3).saturating_sub(Duration::new(5)),
+        Duration::<u8, Unit>::MIN
+    );
+    assert_eq!(
+        Duration::<u8, Unit>::new(200).saturating_mul(2),
+        Duration::<u8, Unit>::MAX
+    );
+}
+
+#[test]
+fn wrapping_and_overflowing_arithmetic() {
+    let (min, max) = (Seconds::new(i64::MIN), Seconds::new(i64::MAX));
+    assert_eq!(max.wrapping_add(Seconds::new(1)), min);
+    assert_eq!(min.wrapping_sub(Seconds::new(1)), max);
+    assert_eq!(max.wrapping_mul(2), Seconds::new(-2));
+    assert_eq!(min.wrapping_div(-1), min);
+    assert_eq!(Seconds::new(-7).wrapping_div(2), Seconds::new(-3));
+
+    assert_eq!(max.overflowing_add(Seconds::new(1)), (min, true));
+    assert_eq!(min.overflowing_sub(Seconds::new(1)), (max, true));
+    assert_eq!(max.overflowing_mul(2), (Seconds::new(-2), true));
+    assert_eq!(min.overflowing_div(-1), (min, true));
+    assert_eq!(
+        Seconds::new(5).overflowing_add(Seconds::new(-7)),
+        (Seconds::new(-2), false)
+    );
+    assert_eq!(
+        Seconds::new(6).overflowing_mul(7),
+        (Seconds::new(42), false)
+    );
+    assert_eq!(
+        Duration::<u8, Unit>::new(3).overflowing_sub(Duration::new(5)),
+        (Duration::new(254), true)
+    );
+}
+
+#[test]
+fn sign_and_absolute_value() {
+    let min = Seconds::new(i64::MIN);
+    assert_eq!(Seconds::new(-5).abs(), Seconds::new(5));
+    assert_eq!(Seconds::new(5).abs(), Seconds::new(5));
+    assert_eq!(Seconds::new(-5).checked_abs(), Some(Seconds::new(5)));
+    assert_eq!(min.checked_abs(), None);
+    assert_eq!(Seconds::MAX.checked_abs(), Some(Seconds::MAX));
+    assert_eq!(Seconds::new(5).checked_neg(), Some(Seconds::new(-5)));
+    assert_eq!(min.checked_neg(), None);
+    assert_eq!(-Seconds::MAX, Seconds::new(i64::MIN + 1));
+    assert_eq!(-Seconds::new(-5), Seconds::new(5));
+
+    assert_eq!(Seconds::new(-5).signum(), -1);
+    assert_eq!(Seconds::new(0).signum(), 0);
+    assert_eq!(Seconds::new(5).signum(), 1);
+    assert_eq!(min.signum(), -1);
+    assert!(min.is_negative() && !min.is_positive());
+    assert!(!Seconds::new(0).is_negative() && !Seconds::new(0).is_positive());
+
+    let f = Duration::<f64, Mili>::new(-2.5);
+    assert_eq!(f.abs(), Duration::<f64, Mili>::new(2.5));
+    assert_eq!(-f, Duration::<f64, Mili>::new(2.5));
+    assert_eq!(f.signum(), -1.0);
+    assert_eq!(Duration::<f64, Mili>::new(0.0).signum(), 1.0);
+    assert!(f.is_negative() && !f.is_positive());
+    assert!(Duration::<f64, Mili>::new(f64::NAN).signum().is_nan());
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn abs_of_min_panics_in_debug() {
+    let _ = Seconds::new(i64::MIN).abs();
+}
+
+#[test]
+fn sum() {
+    let durations = [Seconds::new(5), Seconds::new(-7), Seconds::new(3)];
+    assert_eq!(durations.iter().sum::<Seconds>(), Seconds::new(1));
+    assert_eq!(durations.iter().copied().sum::<Seconds>(), Seconds::new(1));
+    assert_eq!(
+        std::iter::empty::<Seconds>().sum::<Seconds>(),
+        Seconds::new(0)
+    );
+    let floats = [Duration::<f64, Unit>::new(0.5), Duration::new(1.25)];
+    assert_eq!(
+        floats.iter().sum::<Duration<f64, Unit>>(),
+        Duration::<f64, Unit>::new(1.75)
+    );
+}
+
+#[test]
+fn duration_cast_int_to_float() {
+    fn casts(ms: i64) -> [f64; 4] {
+        let d = Miliseconds::new(ms);
+        [
+            DurationCast::<Duration<f64, Unit>>::duration_cast(d)
+                .unwrap()
+                .into_inner(),
+            DurationCast::<Duration<f64, Unit>>::duration_floor(d)
+                .unwrap()
+                .into_inner(),
+            DurationCast::<Duration<f64, Unit>>::duration_ceil(d)
+                .unwrap()
+                .into_inner(),
+            DurationCast::<Duration<f64, Unit>>::duration_round(d)
+                .unwrap()
+                .into_inner(),
+        ]
+    }
+    assert_eq!(casts(1500), [1.5, 1.0, 2.0, 2.0]);
+    assert_eq!(casts(2500), [2.5, 2.0, 3.0, 2.0]);
+    assert_eq!(casts(-2500), [-2.5, -3.0, -2.0, -2.0]);
+    assert_eq!(casts(-3500), [-3.5, -4.0, -3.0, -4.0]);
+    assert_eq!(
+        DurationCast::<Duration<f32, Unit>>::duration_cast(Duration::<i128, Yocto>::new(i128::MAX))
+            .unwrap()
+            .into_inner(),
+        170_141_180_000_000.0
+    );
+}
+
+#[test]
+fn duration_cast_float_to_int() {
+    fn casts(s: f64) -> [Result<i64, DurationCastError>; 4] {
+        let d = Duration::<f64, Unit>::new(s);
+        [
+            DurationCast::<Seconds>::duration_cast(d).map(Duration::into_inner),
+            DurationCast::<Seconds>::duration_floor(d).map(Duration::into_inner),
+            DurationCast::<Seconds>::duration_ceil(d).map(Duration::into_inner),
+            DurationCast::<Seconds>::duration_round(d).map(Duration::into_inner),
+        ]
+    }
+    assert_eq!(casts(2.5), [Ok(2), Ok(2), Ok(3), Ok(2)]);
+    assert_eq!(casts(3.5), [Ok(3), Ok(3), Ok(4), Ok(4)]);
+    assert_eq!(casts(-2.5), [Ok(-2), Ok(-3), Ok(-2), Ok(-2)]);
+    assert_eq!(casts(-3.5), [Ok(-3), Ok(-4), Ok(-3), Ok(-4)]);
+    assert_eq!(casts(-0.5), [Ok(0), Ok(-1), Ok(0), Ok(0)]);
+    assert_eq!(casts(2.75), [Ok(2), Ok(2), Ok(3), Ok(3)]);
+    assert_eq!(
+        DurationCast::<Miliseconds>::duration_cast(Duration::<f64, Unit>::new(-1.25)),
+        Ok(Miliseconds::new(-1250))
+    );
+
+    for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter().copied() {
+        assert_eq!(casts(v), [Err(DurationCastError::NonFinite); 4]);
+    }
+    assert_eq!(casts(1e19), [Err(DurationCastError::Overflow); 4]);
+    assert_eq!(casts(-1e19), [Err(DurationCastError::Underflow); 4]);
+    assert_eq!(
+        DurationCast::<Duration<f32, Unit>>::duration_cast(Duration::<f64, Unit>::new(1e300)),
+        Err(DurationCastError::Overflow)
+    );
+}
+
+#[test]
+fn duration_cast_u128() {
+    assert_eq!(
+        DurationCast::<Duration<u128, Unit>>::duration_cast(Duration::<u128, Nano>::new(u128::MAX))
+            .unwrap()
+            .into_inner(),
+        u128::MAX / 1_000_000_000
+    );
+    assert_eq!(
+        DurationCast::<Duration<u128, Unit>>::duration_ceil(Duration::<u128, Nano>::new(u128::MAX))
+            .unwrap()
+            .into_inner(),
+        u128::MAX / 1_000_000_000 + 1
+    );
+    assert_eq!(
+        DurationCast::<Duration<u128, Nano>>::duration_cast(Duration::<u128, Unit>::new(
+            u128::MAX / 1_000_000_000
+        ))
+        .unwrap()
+        .into_inner(),
+        u128::MAX / 1_000_000_000 * 1_000_000_000
+    );
+    assert_eq!(
+        DurationCast::<Duration<u128, Mili>>::duration_cast(Duration::<u128, Unit>::new(u128::MAX)),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        DurationCast::<Duration<u128, Ratio<2, 1>>>::duration_cast(Duration::<u128, Unit>::new(
+            u128::MAX
+        )),
+        Ok(Duration::new(u128::MAX / 2))
+    );
+    assert_eq!(
+        DurationCast::<Duration<i128, Unit>>::duration_cast(Duration::<u128, Unit>::new(u128::MAX)),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        DurationCast::<Duration<u128, Unit>>::duration_cast(Duration::<i128, Unit>::new(-1)),
+        Err(DurationCastError::Underflow)
+    );
+    assert_eq!(
+        DurationCast::<Duration<i64, Unit>>::duration_floor(Duration::<i128, Mili>::new(-1)),
+        Ok(Seconds::new(-1))
+    );
+    assert!(
+        Duration::<u128, Nano>::new(u128::MAX)
+            > Duration::<u128, Unit>::new(u128::MAX / 1_000_000_000)
+    );
+}
@@ -0,0 +1,132 @@
+use cxx_chrono::duration::*;
+use cxx_chrono::dyn_duration::DynDuration;
+use cxx_chrono::parse::ParseDurationError;
+use cxx_chrono::prelude::*;
+
+fn period(num: i128, den: i128) -> DynRatio {
+    DynRatio::new(num, den).unwrap()
+}
+
+fn values<R: DurationRepr>(d: DynDuration<i64>, to: DynRatio) -> [Result<R, DurationCastError>; 4] {
+    [
+        d.cast_to(to).map(DynDuration::into_inner),
+        d.floor_to(to).map(DynDuration::into_inner),
+        d.ceil_to(to).map(DynDuration::into_inner),
+        d.round_to(to).map(DynDuration::into_inner),
+    ]
+}
+
+#[test]
+fn negative_rounding() {
+    let two = period(2, 1);
+    assert_eq!(
+        values::<i64>(DynDuration::new(-7, period(1, 1)), two),
+        [Ok(-3), Ok(-4), Ok(-3), Ok(-4)]
+    );
+    assert_eq!(
+        values::<i64>(DynDuration::new(-5, period(1, 1)), two),
+        [Ok(-2), Ok(-3), Ok(-2), Ok(-2)]
+    );
+    assert_eq!(
+        values::<i64>(DynDuration::new(-6, period(1, 1)), two),
+        [Ok(-3), Ok(-3), Ok(-3), Ok(-3)]
+    );
+
+    // -19200001 ticks of a 19.2 MHz counter is -1000000052.083 ns
+    let tick = DynDuration::new(-19_200_001, period(1, 19_200_000));
+    assert_eq!(
+        values::<i64>(tick, DynRatio::of::<Nano>()),
+        [
+            Ok(-1_000_000_052),
+            Ok(-1_000_000_053),
+            Ok(-1_000_000_052),
+            Ok(-1_000_000_052)
+        ]
+    );
+    assert_eq!(
+        tick.duration_floor::<i64, Nano>(),
+        Ok(Duration::new(-1_000_000_053))
+    );
+    assert_eq!(
+        tick.duration_ceil::<i64, Mili>(),
+        Ok(Miliseconds::new(-1000))
+    );
+
+    let d = DynDuration::new(-7, period(1, 1))
+        .floor_to::<i64>(two)
+        .unwrap();
+    assert_eq!(d.period(), two);
+    let f = DynDuration::new(-7, period(1, 1))
+        .cast_to::<f64>(two)
+        .unwrap();
+    assert_eq!(f.into_inner(), -3.5);
+}
+
+#[test]
+fn cast_errors() {
+    let second = DynDuration::new(1i64, period(1, 1));
+    assert_eq!(
+        second
+            .cast_to::<i64>(period(0, 1))
+            .map(DynDuration::into_inner),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        DynDuration::new(i64::MAX, period(1, 1)).duration_cast::<i64, Nano>(),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        DynDuration::new(i64::MIN, period(1, 1)).duration_cast::<i64, Nano>(),
+        Err(DurationCastError::Underflow)
+    );
+}
+
+#[test]
+fn exact_conversion() {
+    // Parsing converts to the target Duration exactly, failing with PrecisionLoss otherwise
+    assert_eq!(
+        "1.5s".parse::<Duration<i64, Ratio<1, 60>>>(),
+        Ok(Duration::new(90))
+    );
+    assert_eq!(
+        "-2s".parse::<Duration<i64, Ratio<1, 3>>>(),
+        Ok(Duration::new(-6))
+    );
+    assert_eq!(
+        "0.5s".parse::<Duration<i64, Ratio<1, 3>>>(),
+        Err(ParseDurationError::Cast(DurationCastError::PrecisionLoss))
+    );
+    assert_eq!(
+        "-90s".parse::<Minutes>(),
+        Err(ParseDurationError::Cast(DurationCastError::PrecisionLoss))
+    );
+    assert_eq!("-120s".parse::<Minutes>(), Ok(Minutes::new(-2)));
+}
+
+#[test]
+fn from_duration_round_trip() {
+    let d = DynDuration::from(Miliseconds::new(-1500));
+    assert_eq!(d.period(), DynRatio::of::<Mili>());
+    assert_eq!(d.into_inner(), -1500);
+    assert_eq!(d.duration_cast::<i64, Mili>(), Ok(Miliseconds::new(-1500)));
+    assert_eq!(d.duration_round::<i64, Unit>(), Ok(Seconds::new(-2)));
+
+    let d = DynDuration::from(Duration::<i64, Ratio<1, 3>>::new(7));
+    assert_eq!(d.period(), period(1, 3));
+    assert_eq!(d.duration_cast::<i64, Ratio<1, 3>>(), Ok(Duration::new(7)));
+
+    let d = DynDuration::from(Duration::<f64, Micro>::new(-2.5));
+    assert_eq!(d.duration_cast::<f64, Micro>(), Ok(Duration::new(-2.5)));
+
+    assert_eq!(
+        DynDuration::from(Miliseconds::new(-1500)).to_string(),
+        "-1500ms"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            DynDuration::from(Duration::<i64, Ratio<1, 3>>::new(7))
+        ),
+        "7[1/3]s"
+    );
+}
@@ -0,0 +1,65 @@
+use cxx_chrono::duration::*;
+use cxx_chrono::frequency::*;
+use cxx_chrono::prelude::*;
+
+#[test]
+fn cycles() {
+    assert_eq!(Seconds::new(2) * Hertz::new(50), 100);
+    assert_eq!(Hertz::new(50) * Seconds::new(2), 100);
+    assert_eq!(Miliseconds::new(1500) * Kilohertz::new(48), 72_000);
+    assert_eq!(Miliseconds::new(10) * Hertz::new(50), 0);
+    let samples: i128 = Nanoseconds::new(1_000_000) * Hertz::new(48000);
+    assert_eq!(samples, 48);
+    assert_eq!(Megahertz::new(3) * Duration::<i32, Micro>::new(2), 6);
+    assert_eq!(Duration::<f64, Mili>::new(2.5) * Kilohertz::new(2), 5.0);
+}
+
+#[test]
+fn reciprocals() {
+    let period = 1.0 / Frequency::<f64>::new(50.0);
+    assert_eq!(period.into_inner(), 0.02);
+    let ms: Miliseconds = DurationCast::<Miliseconds>::duration_round(period).unwrap();
+    assert_eq!(ms.into_inner(), 20);
+
+    let period = 1.0 / Frequency::<f64, Kilo>::new(48.0);
+    let ns: Nanoseconds = DurationCast::<Nanoseconds>::duration_round(period).unwrap();
+    assert_eq!(ns.into_inner(), 20833);
+
+    let rate: Frequency<i64, Reciprocal<Unit>> = 48000 / Seconds::new(1);
+    assert_eq!(rate, Hertz::new(48000));
+    let rate = 500 / Miliseconds::new(250);
+    assert_eq!(rate.into_inner(), 2);
+    assert_eq!(rate, Kilohertz::new(2));
+    let period: Duration<i64, Reciprocal<Unit>> = 1 / Hertz::new(50);
+    assert_eq!(period.into_inner(), 0);
+    assert_eq!(1000 / Frequency::<i64, Mili>::new(500), Seconds::new(2000));
+
+    let rate = 500.0 / Duration::<f64, Mili>::new(250.0);
+    assert_eq!(rate, Kilohertz::new(2));
+    let hz: Hertz = rate.frequency_cast().unwrap();
+    assert_eq!(hz.into_inner(), 2000);
+}
+
+#[test]
+fn comparison_and_casts() {
+    assert_eq!(Kilohertz::new(2), Hertz::new(2000));
+    assert!(Kilohertz::new(2) < Hertz::new(2001));
+    assert!(Megahertz::new(1) > Kilohertz::new(999));
+    let mhz: Megahertz = Frequency::<i64, Ratio<192, 10>>::new(1_000_000)
+        .frequency_round()
+        .unwrap();
+    assert_eq!(mhz.into_inner(), 19);
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(Hertz::new(50).to_string(), "50Hz");
+    assert_eq!(Kilohertz::new(19200).to_string(), "19200kHz");
+    assert_eq!(format!("{:?}", Gigahertz::new(3)), "3GHz");
+    assert_eq!(format!("{:#}", Frequency::<i64, Micro>::new(1)), "1uHz");
+    assert_eq!(Frequency::<i64, Micro>::new(1).to_string(), "1µHz");
+    assert_eq!(
+        Frequency::<i64, Ratio<192, 10>>::new(5).to_string(),
+        "5[96/5]Hz"
+    );
+}
@@ -0,0 +1,71 @@
+use cxx_chrono::duration::*;
+use cxx_chrono::go::*;
+use cxx_chrono::parse::ParseDurationError;
+use cxx_chrono::prelude::*;
+
+fn format<R: DurationRepr, P: Period>(d: Duration<R, P>) -> String {
+    Go::new(d).unwrap().to_string()
+}
+
+fn parse(s: &str) -> Result<i128, ParseDurationError> {
+    parse_go::<i128, Nano>(s).map(|d| d.into_inner())
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(format(Nanoseconds::new(0)), "0s");
+    assert_eq!(format(Nanoseconds::new(100)), "100ns");
+    assert_eq!(format(Nanoseconds::new(1_500)), "1.5µs");
+    assert_eq!(format(Seconds::new(90)), "1m30s");
+    assert_eq!(format(Seconds::new(-180)), "-3m0s");
+    assert_eq!(format(Nanoseconds::new(3_723_456_789_000)), "1h2m3.456789s");
+    assert_eq!(
+        Go::new(Duration::<i128, Unit>::new(i128::MAX)).map(|_| ()),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        Go::new(Duration::<f64, Unit>::new(f64::NAN)).map(|_| ()),
+        Err(DurationCastError::NonFinite)
+    );
+}
+
+#[test]
+fn int64_bounds() {
+    assert_eq!(parse("-9223372036854775808ns"), Ok(i64::MIN as i128));
+    assert_eq!(
+        parse("9223372036854775808ns"),
+        Err(ParseDurationError::Cast(DurationCastError::Overflow))
+    );
+    assert_eq!(parse("9223372036854775807ns"), Ok(i64::MAX as i128));
+    assert_eq!(
+        parse("-9223372036854775809ns"),
+        Err(ParseDurationError::Cast(DurationCastError::Underflow))
+    );
+}
+
+#[test]
+fn decimal_forms() {
+    assert_eq!(parse("1.s"), Ok(1_000_000_000));
+    assert_eq!(parse(".5s"), Ok(500_000_000));
+    assert_eq!(parse("1.5h"), Ok(5_400_000_000_000));
+    assert_eq!(parse("."), Err(ParseDurationError::Invalid));
+    assert_eq!(parse("1"), Err(ParseDurationError::Invalid));
+    assert_eq!(parse("1s2"), Err(ParseDurationError::Invalid));
+    assert_eq!(parse("1d"), Err(ParseDurationError::UnknownUnit));
+    assert_eq!(parse(""), Err(ParseDurationError::Empty));
+    assert_eq!(parse("0"), Ok(0));
+}
+
+#[test]
+fn round_trips() {
+    for s in [
+        "1h2m3.456789s",
+        "-3m0s",
+        "1.5µs",
+        "100ns",
+        "2h0m0s",
+        "-999.999999ms",
+    ] {
+        assert_eq!(format(parse_go::<i128, Nano>(s).unwrap()), s);
+    }
+}
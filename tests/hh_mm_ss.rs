@@ -0,0 +1,121 @@
+use cxx_chrono::duration::*;
+use cxx_chrono::hh_mm_ss::*;
+use cxx_chrono::prelude::*;
+
+#[test]
+fn components() {
+    let t = HhMmSs::new(Miliseconds::new(3_723_250)).unwrap();
+    assert!(!t.is_negative());
+    assert_eq!(t.hours(), Hours::new(1));
+    assert_eq!(t.minutes(), Minutes::new(2));
+    assert_eq!(t.seconds(), Seconds::new(3));
+    assert_eq!(t.subseconds().into_inner(), 250);
+    assert_eq!(t.to_duration().into_inner(), 3_723_250);
+    assert_eq!(t.to_string(), "01:02:03.250");
+    assert_eq!(format!("{:?}", t), "HhMmSs(01:02:03.250)");
+    assert_eq!(
+        HhMmSs::new(Hours::new(100)).unwrap().to_string(),
+        "100:00:00"
+    );
+}
+
+#[test]
+fn negative_durations() {
+    let t = HhMmSs::new(Miliseconds::new(-3_723_250)).unwrap();
+    assert!(t.is_negative());
+    assert_eq!(t.hours(), Hours::new(1));
+    assert_eq!(t.minutes(), Minutes::new(2));
+    assert_eq!(t.seconds(), Seconds::new(3));
+    assert_eq!(t.subseconds().into_inner(), 250);
+    assert_eq!(t.to_duration().into_inner(), -3_723_250);
+    assert_eq!(t.to_string(), "-01:02:03.250");
+
+    let t = HhMmSs::new(Miliseconds::new(-1)).unwrap();
+    assert_eq!(t.to_string(), "-00:00:00.001");
+    assert!(!HhMmSs::new(Seconds::new(0)).unwrap().is_negative());
+
+    // The magnitude of i128::MIN is recombined exactly
+    let min = Duration::<i128, Yocto>::new(i128::MIN);
+    assert_eq!(
+        HhMmSs::new(min).unwrap().to_duration().into_inner(),
+        i128::MIN
+    );
+}
+
+#[test]
+fn fractional_width() {
+    assert_eq!(HhMmSs::<Seconds>::FRACTIONAL_WIDTH, 0);
+    assert_eq!(HhMmSs::<Minutes>::FRACTIONAL_WIDTH, 0);
+    assert_eq!(HhMmSs::<Miliseconds>::FRACTIONAL_WIDTH, 3);
+    assert_eq!(HhMmSs::<Duration<i64, Ratio<1, 4>>>::FRACTIONAL_WIDTH, 2);
+    assert_eq!(HhMmSs::<Duration<i64, Ratio<1, 3>>>::FRACTIONAL_WIDTH, 6);
+    assert_eq!(HhMmSs::<Duration<i64, Ratio<1, 60>>>::FRACTIONAL_WIDTH, 6);
+    assert_eq!(HhMmSs::<Duration<i128, Yocto>>::FRACTIONAL_WIDTH, 24);
+
+    let t = HhMmSs::new(Duration::<i64, Ratio<1, 3>>::new(4)).unwrap();
+    assert_eq!(t.seconds(), Seconds::new(1));
+    assert_eq!(t.subseconds().into_inner(), 333_333);
+    assert_eq!(t.to_string(), "00:00:01.333333");
+    assert_eq!(
+        HhMmSs::new(Duration::<i64, Ratio<1, 4>>::new(-3))
+            .unwrap()
+            .to_string(),
+        "-00:00:00.75"
+    );
+    // Floating-point Durations are truncated towards zero
+    assert_eq!(
+        HhMmSs::new(Duration::<f64, Mili>::new(1500.7))
+            .unwrap()
+            .to_string(),
+        "00:00:01.500"
+    );
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(
+        HhMmSs::new(Duration::<i128, Unit>::new(i128::MAX)),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        HhMmSs::new(Duration::<i128, Unit>::new(i128::MIN)),
+        Err(DurationCastError::Underflow)
+    );
+    assert!(HhMmSs::new(Duration::<f64, Unit>::new(f64::NAN)).is_err());
+    assert!(HhMmSs::new(Duration::<f64, Unit>::new(f64::INFINITY)).is_err());
+
+    let max = HhMmSs::new(Duration::<i128, Unit>::new(i64::MAX as i128 * 3600 + 3599)).unwrap();
+    assert_eq!(max.hours(), Hours::new(i64::MAX));
+    assert_eq!(max.seconds(), Seconds::new(59));
+}
+
+#[test]
+fn twelve_hour_clock() {
+    assert!(is_am(Hours::new(0)));
+    assert!(!is_pm(Hours::new(0)));
+    assert!(is_am(Hours::new(11)));
+    assert!(!is_am(Hours::new(12)));
+    assert!(is_pm(Hours::new(12)));
+    assert!(is_pm(Hours::new(23)));
+    assert!(!is_am(Hours::new(24)) && !is_pm(Hours::new(24)));
+    assert!(!is_am(Hours::new(-1)) && !is_pm(Hours::new(-1)));
+
+    assert_eq!(make12(Hours::new(0)), Hours::new(12));
+    assert_eq!(make12(Hours::new(1)), Hours::new(1));
+    assert_eq!(make12(Hours::new(12)), Hours::new(12));
+    assert_eq!(make12(Hours::new(13)), Hours::new(1));
+    assert_eq!(make12(Hours::new(23)), Hours::new(11));
+    assert_eq!(make12(Hours::new(24)), Hours::new(24));
+
+    assert_eq!(make24(Hours::new(12), false), Hours::new(0));
+    assert_eq!(make24(Hours::new(12), true), Hours::new(12));
+    assert_eq!(make24(Hours::new(1), false), Hours::new(1));
+    assert_eq!(make24(Hours::new(1), true), Hours::new(13));
+    assert_eq!(make24(Hours::new(11), true), Hours::new(23));
+    assert_eq!(make24(Hours::new(0), true), Hours::new(0));
+
+    for h in 0..24 {
+        let h = Hours::new(h);
+        assert_eq!(make24(make12(h), is_pm(h)), h);
+    }
+}
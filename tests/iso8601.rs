@@ -0,0 +1,72 @@
+use cxx_chrono::duration::*;
+use cxx_chrono::iso8601::*;
+use cxx_chrono::prelude::*;
+
+fn format<R: DurationRepr, P: Period>(d: Duration<R, P>) -> String {
+    Iso8601::new(d).unwrap().to_string()
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(format(Miliseconds::new(5_400_000)), "PT1H30M");
+    assert_eq!(format(Hours::new(76)), "P3DT4H");
+    assert_eq!(format(Seconds::new(-5)), "-PT5S");
+    assert_eq!(format(Seconds::new(0)), "PT0S");
+    assert_eq!(format(Duration::<f64, Unit>::new(1.5)), "PT1.5S");
+    assert_eq!(
+        format(Duration::<i128, Yocto>::new(1)),
+        "PT0.000000000000000000000001S"
+    );
+}
+
+#[test]
+fn unformattable_durations() {
+    assert_eq!(
+        Iso8601::new(Duration::<f64, Unit>::new(f64::NAN)).map(|_| ()),
+        Err(DurationCastError::NonFinite)
+    );
+    assert_eq!(
+        Iso8601::new(Duration::<f32, Unit>::new(f32::NEG_INFINITY)).map(|_| ()),
+        Err(DurationCastError::NonFinite)
+    );
+    assert_eq!(
+        Iso8601::new(Duration::<i128, Yotta>::new(i128::MAX)).map(|_| ()),
+        Err(DurationCastError::Overflow)
+    );
+    assert_eq!(
+        Iso8601::new(Duration::<i128, Yotta>::new(i128::MIN)).map(|_| ()),
+        Err(DurationCastError::Underflow)
+    );
+}
+
+#[test]
+fn parse_sub_nanosecond_precision() {
+    assert_eq!(
+        parse_iso8601::<f64, Unit>("PT0.0000000001S")
+            .unwrap()
+            .into_inner(),
+        1e-10
+    );
+    assert_eq!(
+        parse_iso8601::<i64, Pico>("PT0.0000000001S")
+            .unwrap()
+            .into_inner(),
+        100
+    );
+    assert_eq!(
+        parse_iso8601::<i64, Nano>("PT0.0000000001S"),
+        Err(cxx_chrono::parse::ParseDurationError::Cast(
+            DurationCastError::PrecisionLoss
+        ))
+    );
+    assert_eq!(
+        parse_iso8601::<i64, Unit>("-P1DT1,5S").unwrap_err(),
+        cxx_chrono::parse::ParseDurationError::Cast(DurationCastError::PrecisionLoss)
+    );
+    assert_eq!(
+        parse_iso8601::<i64, Mili>("-P1DT1,5S")
+            .unwrap()
+            .into_inner(),
+        -86_401_500
+    );
+}
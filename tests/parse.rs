@@ -0,0 +1,47 @@
+use cxx_chrono::duration::*;
+use cxx_chrono::parse::*;
+use cxx_chrono::prelude::*;
+
+#[test]
+fn unit_strings() {
+    assert_eq!("1h30m".parse::<Seconds>().unwrap().into_inner(), 5400);
+    assert_eq!(
+        "-1.5s 250ms".parse::<Miliseconds>().unwrap().into_inner(),
+        -1750
+    );
+    assert_eq!("0".parse::<Seconds>().unwrap().into_inner(), 0);
+    assert_eq!(
+        "1.5s".parse::<Seconds>(),
+        Err(ParseDurationError::Cast(DurationCastError::PrecisionLoss))
+    );
+}
+
+#[test]
+fn sub_nanosecond_precision() {
+    assert_eq!(
+        "1.5ns".parse::<Duration<i64, Pico>>().unwrap().into_inner(),
+        1500
+    );
+    assert_eq!(
+        "1.5ns".parse::<Duration<f64, Nano>>().unwrap().into_inner(),
+        1.5
+    );
+    assert_eq!(
+        "1.000000000000000000000001s"
+            .parse::<Duration<i128, Yocto>>()
+            .unwrap()
+            .into_inner(),
+        1_000_000_000_000_000_000_000_001
+    );
+    assert_eq!(
+        "1.5ns".parse::<Nanoseconds>(),
+        Err(ParseDurationError::Cast(DurationCastError::PrecisionLoss))
+    );
+    // Digits beyond the precision of the sum are truncated for floating-point Reprs only
+    let s = "1.0000000000000000000000000000000000000001s";
+    assert_eq!(s.parse::<Duration<f64, Unit>>().unwrap().into_inner(), 1.0);
+    assert_eq!(
+        s.parse::<Seconds>(),
+        Err(ParseDurationError::Cast(DurationCastError::PrecisionLoss))
+    );
+}
@@ -0,0 +1,232 @@
+use cxx_chrono::ratio::*;
+
+fn period<P: Period>() -> (i128, i128) {
+    (P::NUMERATOR, P::DENOMINATOR)
+}
+
+#[test]
+fn si_aliases() {
+    assert_eq!(period::<Yotta>(), (1_000_000_000_000_000_000_000_000, 1));
+    assert_eq!(period::<Zetta>(), (1_000_000_000_000_000_000_000, 1));
+    assert_eq!(period::<Exa>(), (1_000_000_000_000_000_000, 1));
+    assert_eq!(period::<Peta>(), (1_000_000_000_000_000, 1));
+    assert_eq!(period::<Tera>(), (1_000_000_000_000, 1));
+    assert_eq!(period::<Giga>(), (1_000_000_000, 1));
+    assert_eq!(period::<Mega>(), (1_000_000, 1));
+    assert_eq!(period::<Kilo>(), (1_000, 1));
+    assert_eq!(period::<Hecto>(), (100, 1));
+    assert_eq!(period::<Deca>(), (10, 1));
+    assert_eq!(period::<Unit>(), (1, 1));
+    assert_eq!(period::<Deci>(), (1, 10));
+    assert_eq!(period::<Centi>(), (1, 100));
+    assert_eq!(period::<Mili>(), (1, 1_000));
+    assert_eq!(period::<Micro>(), (1, 1_000_000));
+    assert_eq!(period::<Nano>(), (1, 1_000_000_000));
+    assert_eq!(period::<Pico>(), (1, 1_000_000_000_000));
+    assert_eq!(period::<Femto>(), (1, 1_000_000_000_000_000));
+    assert_eq!(period::<Atto>(), (1, 1_000_000_000_000_000_000));
+    assert_eq!(period::<Zepto>(), (1, 1_000_000_000_000_000_000_000));
+    assert_eq!(period::<Yocto>(), (1, 1_000_000_000_000_000_000_000_000));
+}
+
+#[test]
+fn ratio_normalization() {
+    assert_eq!(period::<Ratio<2, 4>>(), (1, 2));
+    assert_eq!(period::<Ratio<60, 1>>(), (60, 1));
+    assert_eq!(period::<Ratio<1, -2>>(), (-1, 2));
+    assert_eq!(period::<Ratio<-3, -6>>(), (1, 2));
+    assert_eq!(period::<Ratio<-4, 6>>(), (-2, 3));
+    assert_eq!(period::<Ratio<0, 5>>(), (0, 1));
+    assert_eq!(period::<Ratio<{ i128::MAX }, 1>>(), (i128::MAX, 1));
+    assert_eq!(period::<Ratio<{ i128::MIN }, 1>>(), (i128::MIN, 1));
+    assert_eq!(period::<Ratio<1, { i128::MAX }>>(), (1, i128::MAX));
+    assert_eq!(period::<Ratio<{ i128::MIN }, { i128::MIN }>>(), (1, 1));
+}
+
+#[test]
+fn products() {
+    assert_eq!(period::<RatioMultiply<Kilo, Mili>>(), (1, 1));
+    assert_eq!(period::<RatioMultiply<Mega, Micro>>(), (1, 1));
+    assert_eq!(period::<RatioMultiply<Giga, Nano>>(), (1, 1));
+    assert_eq!(period::<RatioMultiply<Tera, Pico>>(), (1, 1));
+    assert_eq!(period::<RatioMultiply<Kilo, Kilo>>(), period::<Mega>());
+    assert_eq!(period::<RatioMultiply<Kilo, Giga>>(), period::<Tera>());
+    assert_eq!(period::<RatioMultiply<Mili, Mili>>(), period::<Micro>());
+    assert_eq!(period::<RatioMultiply<Mili, Nano>>(), period::<Pico>());
+    assert_eq!(
+        period::<RatioMultiply<Giga, Giga>>(),
+        (1_000_000_000_000_000_000, 1)
+    );
+    assert_eq!(
+        period::<RatioMultiply<Nano, Nano>>(),
+        (1, 1_000_000_000_000_000_000)
+    );
+    assert_eq!(period::<RatioMultiply<Pico, Micro>>(), period::<Atto>());
+    assert_eq!(period::<RatioMultiply<Pico, Nano>>(), period::<Zepto>());
+    assert_eq!(period::<RatioMultiply<Exa, Mega>>(), period::<Yotta>());
+    assert_eq!(period::<RatioMultiply<Yotta, Yocto>>(), (1, 1));
+    assert_eq!(
+        period::<RatioMultiply<Ratio<{ i128::MAX }, 3>, Ratio<3, { i128::MAX }>>>(),
+        (1, 1)
+    );
+    assert_eq!(period::<RatioMultiply<Ratio<2, 3>, Ratio<9, 4>>>(), (3, 2));
+    assert_eq!(
+        period::<RatioMultiply<Ratio<-1, 2>, Ratio<1, -3>>>(),
+        (1, 6)
+    );
+}
+
+#[test]
+fn quotients() {
+    assert_eq!(period::<RatioDivide<Kilo, Mili>>(), period::<Mega>());
+    assert_eq!(period::<RatioDivide<Nano, Mili>>(), period::<Micro>());
+    assert_eq!(period::<RatioDivide<Mili, Nano>>(), period::<Mega>());
+    assert_eq!(period::<RatioDivide<Unit, Pico>>(), period::<Tera>());
+    assert_eq!(period::<RatioDivide<Femto, Exa>>(), (1, 10i128.pow(33)));
+    assert_eq!(
+        period::<RatioDivide<Ratio<3600, 1>, Ratio<60, 1>>>(),
+        (60, 1)
+    );
+    assert_eq!(period::<RatioDivide<Ratio<2, 3>, Ratio<-4, 9>>>(), (-3, 2));
+}
+
+#[test]
+fn reciprocals() {
+    assert_eq!(period::<Reciprocal<Kilo>>(), period::<Mili>());
+    assert_eq!(period::<Reciprocal<Pico>>(), period::<Tera>());
+    assert_eq!(period::<Reciprocal<Ratio<-2, 3>>>(), (-3, 2));
+}
+
+#[test]
+fn common_periods() {
+    assert_eq!(period::<CommonPeriod<Unit, Mili>>(), period::<Mili>());
+    assert_eq!(period::<CommonPeriod<Nano, Kilo>>(), period::<Nano>());
+    assert_eq!(
+        period::<CommonPeriod<Ratio<60, 1>, Ratio<3600, 1>>>(),
+        (60, 1)
+    );
+    assert_eq!(period::<CommonPeriod<Mili, Ratio<1, 3>>>(), (1, 3000));
+    assert_eq!(period::<CommonPeriod<Ratio<2, 3>, Ratio<3, 4>>>(), (1, 12));
+}
+
+#[test]
+fn subsecond_precision() {
+    assert_eq!(period::<SubsecondPrecision<Unit>>(), (1, 1));
+    assert_eq!(period::<SubsecondPrecision<Ratio<60, 1>>>(), (1, 1));
+    assert_eq!(period::<SubsecondPrecision<Mili>>(), (1, 1_000));
+    assert_eq!(period::<SubsecondPrecision<Ratio<1, 4>>>(), (1, 100));
+    assert_eq!(period::<SubsecondPrecision<Ratio<1, 3>>>(), (1, 1_000_000));
+    assert_eq!(
+        period::<SubsecondPrecision<Ratio<1, 8_000>>>(),
+        period::<Micro>()
+    );
+    assert_eq!(period::<SubsecondPrecision<Yocto>>(), period::<Yocto>());
+    assert_eq!(SubsecondPrecision::<Atto>::WIDTH, 18);
+}
+
+#[test]
+fn sums_and_differences() {
+    assert_eq!(period::<RatioAdd<Ratio<1, 2>, Ratio<1, 3>>>(), (5, 6));
+    assert_eq!(period::<RatioAdd<Ratio<1, 2>, Ratio<-1, 2>>>(), (0, 1));
+    assert_eq!(period::<RatioAdd<Kilo, Mili>>(), (1_000_001, 1_000));
+    assert_eq!(period::<RatioSubtract<Ratio<1, 2>, Ratio<1, 3>>>(), (1, 6));
+    assert_eq!(period::<RatioSubtract<Mili, Unit>>(), (-999, 1_000));
+    assert_eq!(
+        period::<RatioSubtract<Ratio<{ i128::MIN }, 1>, Ratio<{ i128::MIN }, 1>>>(),
+        (0, 1)
+    );
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(period::<RatioGcd<Ratio<60, 1>, Ratio<3600, 1>>>(), (60, 1));
+    assert_eq!(
+        period::<RatioLcm<Ratio<60, 1>, Ratio<3600, 1>>>(),
+        (3600, 1)
+    );
+    assert_eq!(period::<RatioLcm<Ratio<2, 3>, Ratio<3, 4>>>(), (6, 1));
+    assert_eq!(period::<RatioLcm<Mili, Ratio<1, 3>>>(), (1, 1));
+    assert_eq!(
+        period::<RatioLcm<Ratio<86400, 1>, Ratio<604800, 1>>>(),
+        (604800, 1)
+    );
+}
+
+#[test]
+fn comparisons() {
+    const _: () = assert!(ratio_eq::<Ratio<2, 4>, Ratio<1, 2>>());
+    assert!(ratio_eq::<RatioMultiply<Kilo, Mili>, Unit>());
+    assert!(ratio_ne::<Kilo, Mili>());
+    assert!(ratio_lt::<Nano, Micro>());
+    assert!(!ratio_lt::<Micro, Micro>());
+    assert!(ratio_le::<Micro, Micro>());
+    assert!(ratio_gt::<Ratio<1, -2>, Ratio<-2, 3>>());
+    assert!(ratio_ge::<Tera, Giga>());
+    assert!(ratio_lt::<Yocto, Zepto>());
+    assert!(ratio_lt::<Ratio<-1, 1>, Ratio<0, 1>>());
+    assert!(ratio_lt::<
+        Ratio<{ i128::MIN }, 1>,
+        Ratio<{ i128::MIN + 1 }, 1>,
+    >());
+    assert!(ratio_lt::<
+        Ratio<{ i128::MAX - 1 }, { i128::MAX }>,
+        Ratio<{ i128::MAX }, { i128::MAX - 1 }>,
+    >());
+}
+
+fn assert_divides_evenly<A: DividesEvenly<B>, B: Period>() {}
+
+#[test]
+fn divides_evenly() {
+    assert_divides_evenly::<Mili, Unit>();
+    assert_divides_evenly::<Nano, Ratio<3600, 1>>();
+    assert_divides_evenly::<Ratio<60, 1>, Ratio<86400, 1>>();
+    assert_divides_evenly::<Ratio<1, 3>, Ratio<1, 3>>();
+    assert_divides_evenly::<Yocto, Yotta>();
+    assert_divides_evenly::<Deca, Ratio<60, 1>>();
+    assert_divides_evenly::<Hecto, Ratio<3600, 1>>();
+    assert!(cxx_chrono::ratio::divides_evenly::<Mili, Unit>());
+    assert!(cxx_chrono::ratio::divides_evenly::<Ratio<1, 3>, Ratio<2, 3>>());
+    // Outside of the named Periods, the relation is only available from divides_evenly
+    assert!(cxx_chrono::ratio::divides_evenly::<Ratio<1, 6>, Ratio<1, 3>>());
+    assert!(cxx_chrono::ratio::divides_evenly::<Unit, Ratio<2, 1>>());
+    assert!(!cxx_chrono::ratio::divides_evenly::<Unit, Mili>());
+    assert!(!cxx_chrono::ratio::divides_evenly::<Ratio<2, 3>, Ratio<1, 3>>());
+    assert!(!cxx_chrono::ratio::divides_evenly::<
+        Ratio<60, 1>,
+        Ratio<2629746, 1>,
+    >());
+}
+
+#[test]
+fn dyn_ratio() {
+    let tick = DynRatio::new(10, 192_000_000).unwrap();
+    assert_eq!((tick.numerator(), tick.denominator()), (1, 19_200_000));
+    assert_eq!(DynRatio::new(2, -4), DynRatio::new(-1, 2));
+    assert_eq!(DynRatio::new(1, 0), None);
+    assert_eq!(DynRatio::new(i128::MIN, -1), None);
+    assert_eq!(DynRatio::of::<Mili>(), DynRatio::from(Ratio::<2, 2_000>));
+    assert_eq!(
+        DynRatio::of::<Kilo>().checked_mul(DynRatio::of::<Mili>()),
+        Some(DynRatio::of::<Unit>())
+    );
+    assert_eq!(
+        DynRatio::of::<Pico>().checked_mul(DynRatio::of::<Micro>()),
+        Some(DynRatio::of::<Atto>())
+    );
+    assert_eq!(
+        DynRatio::of::<Yotta>().checked_mul(DynRatio::of::<Yotta>()),
+        None
+    );
+    assert_eq!(
+        DynRatio::of::<Unit>().checked_div(tick),
+        DynRatio::new(19_200_000, 1)
+    );
+    assert_eq!(
+        DynRatio::of::<Unit>().checked_div(DynRatio::of::<Ratio<0, 1>>()),
+        None
+    );
+    assert!(DynRatio::of::<Nano>() < tick);
+    assert!(DynRatio::new(-1, 2) < DynRatio::new(1, 3));
+    assert_eq!(tick.to_string(), "1/19200000");
+}
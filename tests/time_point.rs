@@ -0,0 +1,91 @@
+use cxx_chrono::clock::SystemClock;
+use cxx_chrono::duration::*;
+use cxx_chrono::prelude::*;
+use cxx_chrono::time_point::*;
+
+fn ms(v: i64) -> TimePoint<SystemClock, Miliseconds> {
+    TimePoint::new(Duration::new(v))
+}
+
+fn s(v: i64) -> TimePoint<SystemClock, Seconds> {
+    TimePoint::new(Duration::new(v))
+}
+
+#[test]
+fn rounding_before_epoch() {
+    let conversions = |v| {
+        [
+            time_point_cast::<_, Seconds, _>(ms(v)),
+            time_point_floor::<_, Seconds, _>(ms(v)),
+            time_point_ceil::<_, Seconds, _>(ms(v)),
+            time_point_round::<_, Seconds, _>(ms(v)),
+        ]
+    };
+    assert_eq!(
+        conversions(-1500),
+        [Ok(s(-1)), Ok(s(-2)), Ok(s(-1)), Ok(s(-2))]
+    );
+    assert_eq!(
+        conversions(-2500),
+        [Ok(s(-2)), Ok(s(-3)), Ok(s(-2)), Ok(s(-2))]
+    );
+    assert_eq!(
+        conversions(-1400),
+        [Ok(s(-1)), Ok(s(-2)), Ok(s(-1)), Ok(s(-1))]
+    );
+    assert_eq!(
+        conversions(-1600),
+        [Ok(s(-1)), Ok(s(-2)), Ok(s(-1)), Ok(s(-2))]
+    );
+    assert_eq!(
+        conversions(-2000),
+        [Ok(s(-2)), Ok(s(-2)), Ok(s(-2)), Ok(s(-2))]
+    );
+    assert_eq!(conversions(1500), [Ok(s(1)), Ok(s(1)), Ok(s(2)), Ok(s(2))]);
+}
+
+#[test]
+fn checked_offsets() {
+    assert_eq!(s(5).checked_add(Seconds::new(-7)), Some(s(-2)));
+    assert_eq!(s(5).checked_sub(Seconds::new(7)), Some(s(-2)));
+    assert_eq!(
+        TimePoint::<SystemClock, Seconds>::MAX.checked_add(Seconds::new(1)),
+        None
+    );
+    assert_eq!(s(i64::MIN).checked_sub(Seconds::new(1)), None);
+    assert_eq!(s(i64::MIN).checked_add(Seconds::new(-1)), None);
+    assert_eq!(s(i64::MAX).checked_sub(Seconds::new(-1)), None);
+}
+
+#[test]
+fn saturating_offsets() {
+    let (min, max) = (s(i64::MIN), s(i64::MAX));
+    assert_eq!(s(5).saturating_add(Seconds::new(-7)), s(-2));
+    assert_eq!(max.saturating_add(Seconds::new(1)), max);
+    assert_eq!(max.saturating_sub(Seconds::new(-1)), max);
+    assert_eq!(min.saturating_sub(Seconds::new(1)), min);
+    assert_eq!(min.saturating_add(Seconds::new(-1)), min);
+    assert_eq!(s(-1).saturating_add(Seconds::new(i64::MIN)), min);
+
+    type U8 = TimePoint<SystemClock, Duration<u8, Unit>>;
+    let u = |v| U8::new(Duration::new(v));
+    assert_eq!(u(3).saturating_sub(Duration::new(5)), U8::MIN);
+    assert_eq!(u(250).saturating_add(Duration::new(10)), U8::MAX);
+    assert_eq!(U8::MAX, u(255));
+}
+
+#[test]
+fn wrapping_and_overflowing_offsets() {
+    let (min, max) = (s(i64::MIN), s(i64::MAX));
+    assert_eq!(max.wrapping_add(Seconds::new(1)), min);
+    assert_eq!(min.wrapping_sub(Seconds::new(1)), max);
+    assert_eq!(s(5).wrapping_sub(Seconds::new(7)), s(-2));
+    assert_eq!(max.overflowing_add(Seconds::new(1)), (min, true));
+    assert_eq!(min.overflowing_sub(Seconds::new(1)), (max, true));
+    assert_eq!(s(5).overflowing_add(Seconds::new(-7)), (s(-2), false));
+    assert_eq!(s(5).overflowing_sub(Seconds::new(7)), (s(-2), false));
+
+    let u = |v| TimePoint::<SystemClock, Duration<u8, Unit>>::new(Duration::new(v));
+    assert_eq!(u(3).wrapping_sub(Duration::new(5)), u(254));
+    assert_eq!(u(3).overflowing_sub(Duration::new(5)), (u(254), true));
+}